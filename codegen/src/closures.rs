@@ -0,0 +1,481 @@
+use heck::ToSnakeCase;
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::visit::Visit;
+use syn::visit_mut::{self, VisitMut};
+use syn::{Error, Result};
+
+use crate::util;
+
+pub(crate) fn run(attr: TokenStream, item: TokenStream) -> Result<TokenStream> {
+    let Attr { debug_print, debug_print_filler_output, mod_path, vis } = syn::parse2(attr)?;
+
+    let item: syn::ItemImpl = syn::parse2(item)?;
+
+    let trait_path = match &item.trait_ {
+        None => {
+            return Err(Error::new_spanned(
+                &item.self_ty,
+                "#[closures] can only be used on trait impl blocks",
+            ))
+        }
+        Some((Some(bang), ..)) => {
+            return Err(Error::new_spanned(
+                bang,
+                "#[closures] cannot be used on negated trait impl",
+            ))
+        }
+        Some((None, trait_path, _)) => trait_path,
+    };
+    let struct_ident = table_ident(&item)?;
+
+    let mod_path = mod_path.unwrap_or_else(|| {
+        // deduce the path to the portrait imports module based on the trait path
+        let mut mod_path = trait_path.clone();
+        let mod_name = mod_path.segments.last_mut().expect("path segments should be nonempty");
+        mod_name.ident = format_ident!("{}_portrait", mod_name.ident.to_string().to_snake_case());
+        mod_name.arguments = syn::PathArguments::None;
+        mod_path
+    });
+
+    let mut trait_macro = trait_path.clone();
+    trait_macro.segments.last_mut().expect("path segments should be nonempty").arguments =
+        syn::PathArguments::None;
+
+    let protocol_version = util::protocol_version();
+    let doc = format!(
+        " Implements [`{}`] with a closure for each associated function.",
+        quote!(#trait_macro).to_string().replace(' ', "")
+    );
+
+    let output = quote! {
+        #[doc = #doc]
+        #vis struct #struct_ident {
+            __portrait_table: <#struct_ident as ::portrait::ClosureTable>::Table,
+        }
+
+        const _: () = {
+            #trait_macro! {
+                @TARGET {::portrait::__closures_filler}
+                @IMPORTS { use #mod_path::imports::*; }
+                @FILL_PROTOCOL {#protocol_version}
+                @ARGS {#vis}
+                @IMPL {#item}
+                @DEBUG_PRINT_FILLER_OUTPUT {#debug_print_filler_output}
+            }
+        };
+    };
+
+    if debug_print {
+        println!("{output}");
+    }
+
+    Ok(output)
+}
+
+mod kw {
+    syn::custom_keyword!(MOD_PATH);
+    syn::custom_keyword!(__DEBUG_PRINT);
+    syn::custom_keyword!(DEBUG_PRINT_FILLER_OUTPUT);
+}
+
+struct Attr {
+    debug_print:               bool,
+    debug_print_filler_output: bool,
+    mod_path:                  Option<syn::Path>,
+    vis:                       syn::Visibility,
+}
+
+impl Parse for Attr {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut debug_print = false;
+        let mut debug_print_filler_output = false;
+        let mut mod_path = None;
+
+        while input.peek(syn::Token![@]) {
+            input.parse::<syn::Token![@]>().expect("peek result");
+
+            let lh = input.lookahead1();
+            if lh.peek(kw::__DEBUG_PRINT) {
+                input.parse::<kw::__DEBUG_PRINT>().expect("peek result");
+
+                debug_print = true;
+            } else if lh.peek(kw::DEBUG_PRINT_FILLER_OUTPUT) {
+                input.parse::<kw::DEBUG_PRINT_FILLER_OUTPUT>().expect("peek result");
+
+                debug_print_filler_output = true;
+            } else if lh.peek(kw::MOD_PATH) {
+                input.parse::<kw::MOD_PATH>().expect("peek result");
+
+                let inner;
+                syn::parenthesized!(inner in input);
+                mod_path = Some(inner.parse()?);
+            } else {
+                return Err(lh.error());
+            }
+        }
+
+        let vis = input.parse()?;
+
+        Ok(Self { debug_print, debug_print_filler_output, mod_path, vis })
+    }
+}
+
+/// The struct declared by [`run`] is the self type of the impl block.
+fn table_ident(item_impl: &syn::ItemImpl) -> Result<&syn::Ident> {
+    if let syn::Type::Path(ty) = &*item_impl.self_ty {
+        if ty.qself.is_none() && item_impl.generics.params.is_empty() {
+            if let Some(ident) = ty.path.get_ident() {
+                return Ok(ident);
+            }
+        }
+    }
+
+    Err(Error::new_spanned(
+        &item_impl.self_ty,
+        "#[closures] declares the struct named by the self type, which must be a plain identifier \
+         without generics",
+    ))
+}
+
+/// The generator invoked by the portrait macro with the arguments from [`run`].
+pub(crate) struct Generator {
+    vis:    syn::Visibility,
+    fields: Vec<Field>,
+}
+
+impl Generator {
+    pub(crate) fn new(FillerArgs { vis }: FillerArgs) -> Self { Self { vis, fields: Vec::new() } }
+}
+
+pub(crate) struct FillerArgs {
+    vis: syn::Visibility,
+}
+
+impl Parse for FillerArgs {
+    fn parse(input: ParseStream) -> Result<Self> { Ok(Self { vis: input.parse()? }) }
+}
+
+impl portrait_framework::GenerateImpl for Generator {
+    fn generate_const(
+        &mut self,
+        _ctx: portrait_framework::ImplContext,
+        item: &syn::TraitItemConst,
+    ) -> Result<syn::ImplItemConst> {
+        Err(Error::new_spanned(
+            item,
+            "#[closures] cannot implement associated constants, implement it manually",
+        ))
+    }
+
+    fn generate_fn(
+        &mut self,
+        ctx: portrait_framework::ImplContext,
+        item: &syn::TraitItemFn,
+    ) -> Result<syn::ImplItemFn> {
+        let struct_ident = table_ident(ctx.impl_block)?;
+        let trait_path = &ctx.impl_block.trait_.as_ref().expect("checked in framework").1;
+
+        let field = Field::new(item, struct_ident, trait_path)?;
+        let impl_fn = field.impl_fn(item);
+        self.fields.push(field);
+        Ok(impl_fn)
+    }
+
+    fn generate_type(
+        &mut self,
+        _ctx: portrait_framework::ImplContext,
+        item: &syn::TraitItemType,
+    ) -> Result<syn::ImplItemType> {
+        Err(Error::new_spanned(
+            item,
+            "#[closures] cannot implement associated types, implement it manually",
+        ))
+    }
+
+    fn finish(&mut self, ctx: portrait_framework::ImplContext) -> Result<()> {
+        let Self { vis, fields } = self;
+        let struct_ident = table_ident(ctx.impl_block)?;
+
+        let field_idents: Vec<_> = fields.iter().map(|field| &field.ident).collect();
+        let field_tys = fields.iter().map(Field::boxed_ty);
+        let field_defaults = fields.iter().map(Field::unset);
+        let setters = fields.iter().map(|field| field.setter(vis));
+
+        ctx.emit(syn::parse_quote! {
+            pub struct __PortraitClosureTable {
+                #(#field_idents: #field_tys,)*
+            }
+        });
+        ctx.emit(syn::parse_quote! {
+            impl ::portrait::ClosureTable for #struct_ident {
+                type Table = __PortraitClosureTable;
+            }
+        });
+        ctx.emit(syn::parse_quote! {
+            impl #struct_ident {
+                /// Creates a table where every function panics until it is set.
+                #vis fn new() -> Self {
+                    Self {
+                        __portrait_table: __PortraitClosureTable {
+                            #(#field_idents: #field_defaults,)*
+                        },
+                    }
+                }
+
+                #(#setters)*
+            }
+        });
+        ctx.emit(syn::parse_quote! {
+            impl ::core::default::Default for #struct_ident {
+                fn default() -> Self { Self::new() }
+            }
+        });
+
+        Ok(())
+    }
+}
+
+/// A closure field for an associated function with a receiver.
+struct Field {
+    ident:     syn::Ident,
+    /// `Fn`, `FnMut` or `FnOnce` depending on the receiver.
+    fn_trait:  syn::Ident,
+    /// Lifetime parameters of the function, bound with `for<...>` in the closure type.
+    lifetimes: Vec<syn::Lifetime>,
+    arg_tys:   Vec<syn::Type>,
+    output:    syn::ReturnType,
+}
+
+impl Field {
+    fn new(
+        item: &syn::TraitItemFn,
+        struct_ident: &syn::Ident,
+        trait_path: &syn::Path,
+    ) -> Result<Self> {
+        let sig = &item.sig;
+
+        if let Some(asyncness) = sig.asyncness {
+            return Err(Error::new_spanned(
+                asyncness,
+                "#[closures] cannot implement async functions, implement it manually",
+            ));
+        }
+        if let Some(param) = sig.generics.type_params().next() {
+            return Err(Error::new_spanned(
+                param,
+                "#[closures] cannot implement generic functions, implement it manually",
+            ));
+        }
+        if let Some(param) = sig.generics.const_params().next() {
+            return Err(Error::new_spanned(
+                param,
+                "#[closures] cannot implement generic functions, implement it manually",
+            ));
+        }
+
+        let Some(syn::FnArg::Receiver(receiver)) = sig.inputs.first() else {
+            return Err(Error::new_spanned(
+                sig,
+                "#[closures] cannot implement associated functions without a receiver, implement \
+                 it manually",
+            ));
+        };
+        // `receiver.ty` is also populated for the shorthand receivers,
+        // so typed receivers like `self: &Self` are classified the same way
+        let fn_trait = match &*receiver.ty {
+            syn::Type::Reference(ty) if portrait_framework::is_self(&ty.elem) => {
+                if ty.mutability.is_some() {
+                    format_ident!("FnMut")
+                } else {
+                    format_ident!("Fn")
+                }
+            }
+            ty if portrait_framework::is_self(ty) => format_ident!("FnOnce"),
+            _ => {
+                return Err(Error::new_spanned(
+                    receiver,
+                    "#[closures] only supports `&self`, `&mut self` and `self` receivers",
+                ))
+            }
+        };
+
+        let mut replace_self = ReplaceSelf { struct_ident, trait_path };
+        let mut lifetimes: Vec<_> =
+            sig.generics.lifetimes().map(|param| param.lifetime.clone()).collect();
+        let mut name_elided = NameElidedLifetimes { fresh: Vec::new(), output: None };
+
+        let arg_tys = sig
+            .inputs
+            .iter()
+            .skip(1)
+            .map(|input| match input {
+                syn::FnArg::Typed(pat_ty) => {
+                    if contains_impl_trait(&pat_ty.ty) {
+                        return Err(Error::new_spanned(
+                            &pat_ty.ty,
+                            "#[closures] cannot implement functions with `impl Trait` parameters, \
+                             implement it manually",
+                        ));
+                    }
+                    let mut ty = (*pat_ty.ty).clone();
+                    replace_self.visit_type_mut(&mut ty);
+                    name_elided.visit_type_mut(&mut ty);
+                    Ok(ty)
+                }
+                syn::FnArg::Receiver(receiver) => {
+                    Err(Error::new_spanned(receiver, "unexpected receiver"))
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // elided output lifetimes borrow from `&self`, which the closure does not receive,
+        // so the closure has to return values that outlive the table;
+        // with a `self` receiver they borrow from the only lifetime in the parameters
+        name_elided.output = Some(match (&*receiver.ty, &name_elided.fresh[..]) {
+            (syn::Type::Reference(_), _) => syn::Lifetime::new("'static", Span::call_site()),
+            (_, [lifetime]) if lifetimes.is_empty() => lifetime.clone(),
+            (_, []) if lifetimes.len() == 1 => lifetimes[0].clone(),
+            _ => syn::Lifetime::new("'static", Span::call_site()),
+        });
+        lifetimes.append(&mut name_elided.fresh);
+
+        let mut output = sig.output.clone();
+        replace_self.visit_return_type_mut(&mut output);
+        name_elided.visit_return_type_mut(&mut output);
+
+        Ok(Self { ident: sig.ident.clone(), fn_trait, lifetimes, arg_tys, output })
+    }
+
+    /// The closure trait bound, e.g. `for<'a> Fn(&'a str) -> u32`.
+    fn bound(&self) -> TokenStream {
+        let Self { fn_trait, lifetimes, arg_tys, output, .. } = self;
+        let for_lifetimes = (!lifetimes.is_empty()).then(|| quote!(for<#(#lifetimes),*>));
+        quote!(#for_lifetimes #fn_trait(#(#arg_tys),*) #output)
+    }
+
+    fn boxed_ty(&self) -> TokenStream {
+        let bound = self.bound();
+        quote!(::std::boxed::Box<dyn #bound>)
+    }
+
+    /// The closure that panics when the function is called before it is set.
+    fn unset(&self) -> TokenStream {
+        let message = format!("`{}` is not set", self.ident);
+        let args = self.arg_tys.iter().map(|_| quote!(_));
+        quote!(::std::boxed::Box::new(|#(#args),*| ::core::unimplemented!(#message)))
+    }
+
+    fn setter(&self, vis: &syn::Visibility) -> TokenStream {
+        let ident = &self.ident;
+        let setter_ident = format_ident!("with_{}", ident.to_string().trim_start_matches("r#"));
+        let bound = self.bound();
+        let doc = format!(" Sets the implementation of `{ident}`.");
+        quote! {
+            #[doc = #doc]
+            #vis fn #setter_ident(mut self, f: impl #bound + 'static) -> Self {
+                self.__portrait_table.#ident = ::std::boxed::Box::new(f);
+                self
+            }
+        }
+    }
+
+    fn impl_fn(&self, item: &syn::TraitItemFn) -> syn::ImplItemFn {
+        let mut sig = item.sig.clone();
+        // the body is generated at the call site
+        util::set_sig_arg_span(&mut sig, Span::call_site())
+            .expect("patterns remain valid with a different span");
+        let args = util::normalize_arg_pats(&mut sig).into_iter().skip(1);
+
+        let ident = &self.ident;
+        syn::ImplItemFn {
+            attrs: item.attrs.iter().filter(|attr| attr.path().is_ident("cfg")).cloned().collect(),
+            vis: syn::Visibility::Inherited,
+            defaultness: None,
+            sig,
+            block: syn::parse_quote!({ (self.__portrait_table.#ident)(#(#args),*) }),
+        }
+    }
+}
+
+fn contains_impl_trait(ty: &syn::Type) -> bool {
+    struct Visitor(bool);
+
+    impl<'ast> Visit<'ast> for Visitor {
+        fn visit_type_impl_trait(&mut self, _: &'ast syn::TypeImplTrait) { self.0 = true; }
+    }
+
+    let mut visitor = Visitor(false);
+    visitor.visit_type(ty);
+    visitor.0
+}
+
+/// Names the elided lifetimes in the closure signature,
+/// since the elision rules of `Fn(...)` bounds differ from those of methods.
+///
+/// Elided lifetimes in the parameters get fresh names to be bound with `for<...>`,
+/// and elided lifetimes in the output are replaced with `output`.
+struct NameElidedLifetimes {
+    fresh:  Vec<syn::Lifetime>,
+    output: Option<syn::Lifetime>,
+}
+
+impl NameElidedLifetimes {
+    fn name(&mut self, span: Span) -> syn::Lifetime {
+        if let Some(output) = &self.output {
+            return output.clone();
+        }
+        let lifetime = syn::Lifetime::new(&format!("'__portrait_{}", self.fresh.len()), span);
+        self.fresh.push(lifetime.clone());
+        lifetime
+    }
+}
+
+impl VisitMut for NameElidedLifetimes {
+    fn visit_type_reference_mut(&mut self, ty: &mut syn::TypeReference) {
+        if ty.lifetime.is_none() {
+            ty.lifetime = Some(self.name(ty.and_token.span));
+        }
+        visit_mut::visit_type_reference_mut(self, ty);
+    }
+
+    fn visit_lifetime_mut(&mut self, lifetime: &mut syn::Lifetime) {
+        if lifetime.ident == "_" {
+            *lifetime = self.name(lifetime.apostrophe);
+        }
+    }
+
+    // lifetimes elided in nested function signatures are bound by those signatures
+    fn visit_type_bare_fn_mut(&mut self, _: &mut syn::TypeBareFn) {}
+
+    fn visit_parenthesized_generic_arguments_mut(
+        &mut self,
+        _: &mut syn::ParenthesizedGenericArguments,
+    ) {
+    }
+}
+
+/// Rewrites `Self` to the struct and `Self::Assoc` to `<Struct as Trait>::Assoc`,
+/// since `Self` refers to the table in its field types.
+struct ReplaceSelf<'t> {
+    struct_ident: &'t syn::Ident,
+    trait_path:   &'t syn::Path,
+}
+
+impl VisitMut for ReplaceSelf<'_> {
+    fn visit_type_path_mut(&mut self, ty: &mut syn::TypePath) {
+        if ty.qself.is_none() && ty.path.segments[0].ident == "Self" {
+            let struct_ident = self.struct_ident;
+            let trait_path = self.trait_path;
+            let rest: Vec<_> = ty.path.segments.iter().skip(1).collect();
+            *ty = if rest.is_empty() {
+                syn::parse_quote!(#struct_ident)
+            } else {
+                syn::parse_quote!(<#struct_ident as #trait_path>::#(#rest)::*)
+            };
+        }
+
+        visit_mut::visit_type_path_mut(self, ty);
+    }
+}
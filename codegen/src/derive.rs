@@ -7,42 +7,44 @@ use syn::Result;
 use crate::util;
 
 pub(crate) fn run(attr: TokenStream, item: TokenStream) -> Result<TokenStream> {
-    let Attr {
-        debug_print,
-        debug_print_filler_output,
-        mod_path,
-        trait_path,
-        attr_path,
-        args: attr_args,
-    } = syn::parse2(attr)?;
+    let Attr { debug_print, debug_print_filler_output, mod_path, derives } = syn::parse2(attr)?;
 
     let item: syn::DeriveInput = syn::parse2(item)?;
 
-    let mod_path = mod_path.unwrap_or_else(|| {
-        // deduce the path to the portrait imports module based on the trait path
-        let mut mod_path = trait_path.clone();
-        let mod_name = mod_path.segments.last_mut().expect("path segments should be nonempty");
-        mod_name.ident = format_ident!("{}_portrait", mod_name.ident.to_string().to_snake_case());
-        mod_name.arguments = syn::PathArguments::None;
-        mod_path
-    });
-
     let item_stripped = util::strip_attr("portrait", &item, syn::visit_mut::visit_derive_input_mut);
 
+    let protocol_version = util::protocol_version();
+
+    let derives = derives.iter().map(|Derive { trait_path, attr_path, args: attr_args }| {
+        let mod_path = mod_path.clone().unwrap_or_else(|| {
+            // deduce the path to the portrait imports module based on the trait path
+            let mut mod_path = trait_path.clone();
+            let mod_name = mod_path.segments.last_mut().expect("path segments should be nonempty");
+            mod_name.ident =
+                format_ident!("{}_portrait", mod_name.ident.to_string().to_snake_case());
+            mod_name.arguments = syn::PathArguments::None;
+            mod_path
+        });
+
+        quote! {
+            const _: () = {
+                #trait_path! {
+                    @TARGET {#attr_path}
+                    @IMPORTS { use #mod_path::imports::*; }
+                    @FILL_PROTOCOL {#protocol_version}
+                    @TRAIT_PATH {#trait_path}
+                    @ARGS {#attr_args}
+                    @INPUT {#item}
+                    @DEBUG_PRINT_FILLER_OUTPUT {#debug_print_filler_output}
+                }
+            };
+        }
+    });
+
     let output = quote! {
         #item_stripped
 
-        const _: () = {
-            use #mod_path::imports::*;
-
-            #trait_path! {
-                @TARGET {#attr_path}
-                @TRAIT_PATH {#trait_path}
-                @ARGS {#attr_args}
-                @INPUT {#item}
-                @DEBUG_PRINT_FILLER_OUTPUT {#debug_print_filler_output}
-            }
-        };
+        #(#derives)*
     };
 
     if debug_print {
@@ -63,9 +65,14 @@ struct Attr {
     debug_print:               bool,
     debug_print_filler_output: bool,
     mod_path:                  Option<syn::Path>,
-    trait_path:                syn::Path,
-    attr_path:                 syn::Path,
-    args:                      Option<TokenStream>,
+    derives:                   Vec<Derive>,
+}
+
+/// A trait to derive with the given filler.
+struct Derive {
+    trait_path: syn::Path,
+    attr_path:  syn::Path,
+    args:       Option<TokenStream>,
 }
 
 impl Parse for Attr {
@@ -97,17 +104,54 @@ impl Parse for Attr {
             }
         }
 
-        let trait_path = input.parse()?;
-        let _for_token: kw::with = input.parse()?;
-        let attr_path = input.parse()?;
+        // Traits without their own `with` clause use the next filler in the list,
+        // e.g. `Foo, Bar with filler` derives both `Foo` and `Bar` with `filler`.
+        let mut derives = Vec::new();
+        let mut pending_traits: Vec<syn::Path> = Vec::new();
+        loop {
+            pending_traits.push(input.parse()?);
+
+            if input.peek(kw::with) {
+                input.parse::<kw::with>().expect("peek result");
+                let attr_path: syn::Path = input.parse()?;
+
+                let mut args = None;
+                if input.peek(syn::token::Paren) {
+                    let inner;
+                    syn::parenthesized!(inner in input);
+                    args = Some(inner.parse()?);
+                }
+
+                derives.extend(pending_traits.drain(..).map(|trait_path| Derive {
+                    trait_path,
+                    attr_path: attr_path.clone(),
+                    args: args.clone(),
+                }));
+            }
+
+            if input.is_empty() {
+                break;
+            }
+            input.parse::<syn::Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+        }
+
+        if let Some(trait_path) = pending_traits.first() {
+            return Err(syn::Error::new_spanned(
+                trait_path,
+                "Expected `with` followed by the filler to derive this trait with",
+            ));
+        }
 
-        let mut args = None;
-        if !input.is_empty() {
-            let inner;
-            syn::parenthesized!(inner in input);
-            args = Some(inner.parse()?);
+        if let (Some(mod_path), [_, _, ..]) = (&mod_path, &derives[..]) {
+            return Err(syn::Error::new_spanned(
+                mod_path,
+                "@MOD_PATH can only be used when deriving a single trait",
+            ));
         }
 
-        Ok(Self { debug_print, debug_print_filler_output, mod_path, trait_path, attr_path, args })
+        Ok(Self { debug_print, debug_print_filler_output, mod_path, derives })
     }
 }
@@ -24,6 +24,7 @@ impl GenerateDerive for Generator {
     ) -> syn::Result<syn::ImplItemFn> {
         let fn_args = util::parse_grouped_attr::<FnArgs>(&item.attrs, "derive_delegate")?;
 
+        let mut sig = item.sig.clone();
         let output_ty: syn::Type = if let syn::ReturnType::Type(_, ty) = &item.sig.output {
             if fn_args.with_try.0.is_some() {
                 let make_err = || {
@@ -58,10 +59,17 @@ impl GenerateDerive for Generator {
             }
             syn::Data::Enum(data) => transform_enum(item, trait_path, &fn_args, &output_ty, data)?,
             syn::Data::Union(data) => {
-                return Err(syn::Error::new_spanned(
-                    data.union_token,
-                    "derive_delegate does not support unions",
-                ))
+                let union_args =
+                    util::parse_grouped_attr::<UnionArgs>(&input.attrs, "derive_delegate")?;
+                transform_union(
+                    item,
+                    &mut sig,
+                    trait_path,
+                    &fn_args,
+                    &union_args,
+                    &output_ty,
+                    data,
+                )?
             }
         };
 
@@ -100,7 +108,7 @@ impl GenerateDerive for Generator {
                 .collect(),
             vis:         syn::Visibility::Inherited,
             defaultness: None,
-            sig:         item.sig.clone(),
+            sig,
             block:       syn::Block { brace_token: Default::default(), stmts },
         })
     }
@@ -152,7 +160,60 @@ impl GenerateDerive for Generator {
                     }
                 }
             }
-            _ => {}
+            syn::Data::Union(data) => {
+                for field in &data.fields.named {
+                    add_generic_predicate(generics_where, trait_path, field);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn finish(
+        &mut self,
+        DeriveContext { input, all_trait_items, .. }: DeriveContext,
+    ) -> syn::Result<()> {
+        // field options that do not apply to the container are rejected instead of ignored
+        let (kind, fields): (_, Vec<&syn::Field>) = match &input.data {
+            syn::Data::Struct(data) => ("struct", data.fields.iter().collect()),
+            syn::Data::Enum(data) => {
+                ("enum", data.variants.iter().flat_map(|variant| &variant.fields).collect())
+            }
+            syn::Data::Union(data) => ("union", data.fields.named.iter().collect()),
+        };
+
+        let mut uses_priority = false;
+        for item in all_trait_items {
+            if let syn::TraitItem::Fn(item) = item {
+                let fn_args = util::parse_grouped_attr::<FnArgs>(&item.attrs, "derive_delegate")?;
+                uses_priority |= matches!(fn_args.order.0, Some((_, Order::Priority)));
+            }
+        }
+
+        for field in fields {
+            let field_args =
+                util::parse_grouped_attr::<FieldArgs>(&field.attrs, "derive_delegate")?;
+            if let (Some((span, _)), "struct" | "enum") = (&field_args.tag.0, kind) {
+                return Err(syn::Error::new(
+                    *span,
+                    format!("`tag` is only supported on union fields, not on {kind} fields"),
+                ));
+            }
+            if let (Some((span, _)), "union") = (&field_args.priority.0, kind) {
+                return Err(syn::Error::new(
+                    *span,
+                    "`priority` is not supported on union fields because a union delegates to a \
+                     single field",
+                ));
+            }
+            if let (Some((span, _)), false) = (&field_args.priority.0, uses_priority) {
+                return Err(syn::Error::new(
+                    *span,
+                    "`priority` has no effect because no function of the trait uses \
+                     `#[portrait(derive_delegate(order = priority))]`",
+                ));
+            }
         }
 
         Ok(())
@@ -215,7 +276,7 @@ fn transform_struct(
         output_ty,
         &data.fields,
         &syn::parse_quote!(Self),
-        false,
+        Container::Struct,
     )?);
     Ok(stmts)
 }
@@ -244,7 +305,7 @@ fn transform_enum(
             output_ty,
             &variant.fields,
             &syn::parse_quote!(Self::#variant_ident),
-            true,
+            Container::Enum,
         )?;
 
         let mut block = syn::Expr::Block(syn::ExprBlock {
@@ -341,6 +402,113 @@ fn transform_enum(
     Ok(vec![match_stmt])
 }
 
+fn transform_union(
+    item: &syn::TraitItemFn,
+    sig: &mut syn::Signature,
+    trait_path: &syn::Path,
+    fn_args: &FnArgs,
+    union_args: &UnionArgs,
+    output_ty: &syn::Type,
+    data: &syn::DataUnion,
+) -> syn::Result<Vec<syn::Stmt>> {
+    let Some((_, union_tag)) = &union_args.union_tag.0 else {
+        return Err(syn::Error::new_spanned(
+            data.union_token,
+            "Deriving delegates for unions requires `#[portrait(derive_delegate(union_tag = \
+             ...))]` on the union to identify the initialized field",
+        ));
+    };
+    if union_args.unsafe_union.0.is_none() {
+        return Err(syn::Error::new_spanned(
+            data.union_token,
+            "Deriving delegates for unions accesses union fields in `unsafe` blocks. Add \
+             `#[portrait(derive_delegate(unsafe_union))]` on the union to acknowledge that \
+             `union_tag` always identifies the initialized field",
+        ));
+    }
+
+    // `self` in `union_tag` is resolved with the hygiene of the user-provided expression.
+    util::set_sig_arg_span(sig, union_tag.span())?;
+    let item = &syn::TraitItemFn { sig: sig.clone(), ..item.clone() };
+
+    let Some(receiver) = item.sig.receiver() else {
+        return Err(syn::Error::new_spanned(
+            &item.sig,
+            "Cannot derive union delegates for associated functions without receivers",
+        ));
+    };
+    if receiver.colon_token.is_some() {
+        return Err(syn::Error::new_spanned(
+            receiver,
+            "Cannot derive union delegates for associated functions with typed receivers",
+        ));
+    }
+    let receiver_ref = receiver.reference.as_ref().map(|(and, _)| and);
+    let receiver_mut = receiver.mutability;
+    let receiver_self = receiver.self_token;
+
+    let mut arms = Vec::new();
+    for field in &data.fields.named {
+        let field_args = util::parse_grouped_attr::<FieldArgs>(&field.attrs, "derive_delegate")?;
+        let Some((_, tag)) = field_args.tag.0 else {
+            return Err(syn::Error::new_spanned(
+                field,
+                "Every union field must specify its tag value with \
+                 `#[portrait(derive_delegate(tag = ...))]`",
+            ));
+        };
+
+        let field_ident = field.ident.as_ref().expect("union fields are named");
+        let fields = syn::Fields::Named(syn::FieldsNamed {
+            brace_token: data.fields.brace_token,
+            named:       [field.clone()].into_iter().collect(),
+        });
+
+        let mut stmts: Vec<syn::Stmt> = vec![syn::parse_quote! {
+            let __portrait_self_0 = unsafe { #receiver_ref #receiver_mut #receiver_self.#field_ident };
+        }];
+        stmts.extend(transform_return(
+            item,
+            fn_args,
+            trait_path,
+            output_ty,
+            &fields,
+            &syn::parse_quote!(Self),
+            Container::Union,
+        )?);
+
+        arms.push(syn::Arm {
+            attrs:           cfg_attrs(&field.attrs),
+            pat:             tag,
+            guard:           None,
+            fat_arrow_token: syn::Token![=>](field.span()),
+            body:            Box::new(syn::Expr::Block(syn::ExprBlock {
+                attrs: Vec::new(),
+                label: None,
+                block: syn::Block { brace_token: syn::token::Brace(field.span()), stmts },
+            })),
+            comma:           Some(syn::Token![,](field.span())),
+        });
+    }
+
+    arms.push(syn::parse_quote_spanned! { union_tag.span() =>
+        #[allow(unreachable_patterns)]
+        _ => ::core::unreachable!("union tag does not match any field"),
+    });
+
+    let match_stmt = syn::Stmt::Expr(
+        syn::Expr::Match(syn::ExprMatch {
+            attrs: Vec::new(),
+            match_token: syn::Token![match](Span::call_site()),
+            expr: Box::new(union_tag.clone()),
+            brace_token: syn::token::Brace(Span::call_site()),
+            arms,
+        }),
+        None,
+    );
+    Ok(vec![match_stmt])
+}
+
 fn transform_return(
     item: &syn::TraitItemFn,
     fn_args: &FnArgs,
@@ -348,15 +516,95 @@ fn transform_return(
     output_ty: &syn::Type,
     fields: &syn::Fields,
     ctor_path: &syn::Path,
-    is_refutable: bool,
+    container: Container,
 ) -> syn::Result<Vec<syn::Stmt>> {
+    // validate the options before the single-field shortcut skips them
+    let strategy_spans: Vec<Span> = [
+        fn_args.reduce.0.as_ref().map(|(span, _)| *span),
+        fn_args.short_circuit.0.as_ref().map(|(span, _)| *span),
+        fn_args.gather.0.as_ref().map(|(span, _)| *span),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+    if let [_, span, ..] = strategy_spans[..] {
+        return Err(syn::Error::new(
+            span,
+            "Only one of `reduce`, short-circuiting strategies, `collect` or `chain` can be used",
+        ));
+    }
+
+    let is_refutable = !matches!(container, Container::Struct);
     let exprs = transform_arg_fields(item, fn_args, trait_path, fields, ctor_path, is_refutable)?;
 
-    let exprs = match exprs.try_into() {
-        Ok::<[_; 1], _>([(single, _, _)]) => return Ok(vec![syn::Stmt::Expr(single, None)]),
-        Err(err) => err,
+    // a union arm only delegates to one field,
+    // but it still has to construct `Self` and apply `reduce_base`
+    let returns_self = portrait_framework::is_self(output_ty);
+    let exprs = if matches!(container, Container::Union)
+        && (returns_self || fn_args.reduce_base.0.is_some())
+    {
+        exprs
+    } else {
+        match exprs.try_into() {
+            Ok::<[_; 1], _>([(single, _, _)]) => return Ok(vec![syn::Stmt::Expr(single, None)]),
+            Err(err) => err,
+        }
     };
 
+    if let Some((span, gather)) = &fn_args.gather.0 {
+        let chain = exprs
+            .into_iter()
+            .map(|(expr, _, _)| expr)
+            .reduce(|stack, expr| {
+                syn::parse_quote_spanned! { *span =>
+                    ::core::iter::Iterator::chain(::core::iter::IntoIterator::into_iter(#stack), #expr)
+                }
+            })
+            .unwrap_or_else(|| syn::parse_quote_spanned!(*span => ::core::iter::empty()));
+
+        let expr = match gather {
+            Gather::Collect => syn::parse_quote_spanned! { *span =>
+                ::core::iter::Iterator::collect(::core::iter::IntoIterator::into_iter(#chain))
+            },
+            Gather::Chain => chain,
+        };
+        return Ok(vec![syn::Stmt::Expr(expr, None)]);
+    }
+
+    if let Some((span, short_circuit)) = &fn_args.short_circuit.0 {
+        let pred = short_circuit.predicate(*span);
+        let label = syn::Lifetime::new("'__portrait_short_circuit", Span::call_site());
+
+        let mut exprs_iter = exprs.into_iter();
+        let Some((last, _, _)) = exprs_iter.next_back() else {
+            return Err(syn::Error::new(
+                *span,
+                "Short-circuiting strategies are not applicable for empty structs",
+            ));
+        };
+
+        let mut stmts: Vec<syn::Stmt> = Vec::new();
+        for (expr, _, field) in exprs_iter {
+            stmts.extend::<[syn::Stmt; 2]>([
+                syn::parse_quote_spanned! { field.span() =>
+                    let __portrait_value = #expr;
+                },
+                syn::parse_quote_spanned! { field.span() =>
+                    if (#pred)(&__portrait_value) {
+                        break #label __portrait_value;
+                    }
+                },
+            ]);
+        }
+        stmts.push(syn::Stmt::Expr(last, None));
+
+        return Ok(vec![syn::parse_quote_spanned! { *span =>
+            #label: {
+                #(#stmts)*
+            }
+        }]);
+    }
+
     Ok(match (&fn_args.reduce.0, output_ty) {
         (Some((_, reduce_fn)), _) => {
             let mut exprs_iter = exprs.into_iter();
@@ -436,9 +684,28 @@ fn transform_arg_fields<'t>(
     ctor_path: &syn::Path,
     is_refutable: bool,
 ) -> syn::Result<Vec<(syn::Expr, usize, &'t syn::Field)>> {
-    fields
-        .iter()
-        .enumerate()
+    let mut ordered: Vec<(usize, &syn::Field)> = fields.iter().enumerate().collect();
+    match fn_args.order.0 {
+        None => {}
+        Some((_, Order::Reverse)) => ordered.reverse(),
+        Some((_, Order::Priority)) => {
+            let priorities = ordered
+                .iter()
+                .map(|(_, field)| {
+                    let field_args =
+                        util::parse_grouped_attr::<FieldArgs>(&field.attrs, "derive_delegate")?;
+                    Ok(field_args.priority.0.map_or(0, |(_, priority)| priority))
+                })
+                .collect::<syn::Result<Vec<i64>>>()?;
+            let mut keyed: Vec<_> = priorities.into_iter().zip(ordered).collect();
+            // `sort_by_key` is stable, so fields with equal priority keep the declaration order.
+            keyed.sort_by_key(|&(priority, _)| std::cmp::Reverse(priority));
+            ordered = keyed.into_iter().map(|(_, field)| field).collect();
+        }
+    }
+
+    ordered
+        .into_iter()
         .map(|(ord, field)| {
             let mut expr = syn::Expr::Call(syn::ExprCall {
                 attrs:       Vec::new(),
@@ -456,6 +723,15 @@ fn transform_arg_fields<'t>(
                     .collect::<syn::Result<_>>()?,
             });
 
+            if let Some(asyncness) = item.sig.asyncness {
+                expr = syn::Expr::Await(syn::ExprAwait {
+                    attrs:       Vec::new(),
+                    base:        Box::new(expr),
+                    dot_token:   syn::Token![.](asyncness.span()),
+                    await_token: syn::Token![await](asyncness.span()),
+                })
+            }
+
             if let Some((with_try_span, _)) = fn_args.with_try.0 {
                 expr = syn::Expr::Try(syn::ExprTry {
                     attrs:          Vec::new(),
@@ -480,7 +756,7 @@ fn transform_arg(
 
     let ret = match arg {
         syn::FnArg::Receiver(_) => syn::Expr::Path(syn::parse_quote!(#field_ident)),
-        syn::FnArg::Typed(arg) if is_self_ty(&arg.ty) => {
+        syn::FnArg::Typed(arg) if portrait_framework::is_self_or_ref(&arg.ty) => {
             if is_refutable {
                 return Err(syn::Error::new_spanned(
                     arg,
@@ -488,6 +764,14 @@ fn transform_arg(
                 ));
             }
 
+            let syn::Pat::Ident(arg_ident) = &*arg.pat else {
+                return Err(syn::Error::new_spanned(
+                    &arg.pat,
+                    "Cannot derive delegate for traits with non-identifier-pattern parameters",
+                ));
+            };
+            let arg_ident = &arg_ident.ident;
+
             let member = match &field.ident {
                 Some(ident) => syn::Member::Named(ident.clone()),
                 None => syn::Member::Unnamed(syn::Index {
@@ -496,7 +780,7 @@ fn transform_arg(
                 }),
             };
             syn::Expr::Block(syn::parse_quote! {{
-                let #ctor_path { #member: __portrait_other, .. } = self;
+                let #ctor_path { #member: __portrait_other, .. } = #arg_ident;
                 __portrait_other
             }})
         }
@@ -517,26 +801,35 @@ fn transform_arg(
     Ok(ret)
 }
 
-fn is_self_ty(ty: &syn::Type) -> bool {
-    match ty {
-        syn::Type::Path(ty) => ty.path.is_ident("Self"),
-        syn::Type::Reference(ty) => is_self_ty(&ty.elem),
-        _ => false,
-    }
-}
-
 mod kw {
     syn::custom_keyword!(reduce);
     syn::custom_keyword!(reduce_base);
     syn::custom_keyword!(enum_either);
+    syn::custom_keyword!(first_some);
+    syn::custom_keyword!(first_ok);
+    syn::custom_keyword!(first_err);
+    syn::custom_keyword!(all);
+    syn::custom_keyword!(any);
+    syn::custom_keyword!(until);
+    syn::custom_keyword!(collect);
+    syn::custom_keyword!(chain);
+    syn::custom_keyword!(order);
+    syn::custom_keyword!(reverse);
+    syn::custom_keyword!(priority);
+    syn::custom_keyword!(union_tag);
+    syn::custom_keyword!(unsafe_union);
+    syn::custom_keyword!(tag);
 }
 
 #[derive(Default)]
 struct FnArgs {
-    reduce:      util::Once<syn::Expr>,
-    reduce_base: util::Once<syn::Expr>,
-    with_try:    util::Once<Option<syn::Expr>>,
-    enum_either: util::Once<Option<EnumEither>>,
+    reduce:        util::Once<syn::Expr>,
+    reduce_base:   util::Once<syn::Expr>,
+    with_try:      util::Once<Option<syn::Expr>>,
+    enum_either:   util::Once<Option<EnumEither>>,
+    short_circuit: util::Once<ShortCircuit>,
+    gather:        util::Once<Gather>,
+    order:         util::Once<Order>,
 }
 
 impl util::ParseArgs for FnArgs {
@@ -573,7 +866,147 @@ impl util::ParseArgs for FnArgs {
                 Ok(EnumEither { paren, left: inner.parse()?, _comma: inner.parse()?, right: inner.parse()? })
             }).transpose()?;
             self.enum_either.set(value, key.span())?;
-        }else {
+        } else if lh.peek(kw::first_some) {
+            let key: kw::first_some = input.parse()?;
+            self.short_circuit.set(ShortCircuit::FirstSome, key.span())?;
+        } else if lh.peek(kw::first_ok) {
+            let key: kw::first_ok = input.parse()?;
+            self.short_circuit.set(ShortCircuit::FirstOk, key.span())?;
+        } else if lh.peek(kw::first_err) {
+            let key: kw::first_err = input.parse()?;
+            self.short_circuit.set(ShortCircuit::FirstErr, key.span())?;
+        } else if lh.peek(kw::all) {
+            let key: kw::all = input.parse()?;
+            self.short_circuit.set(ShortCircuit::All, key.span())?;
+        } else if lh.peek(kw::any) {
+            let key: kw::any = input.parse()?;
+            self.short_circuit.set(ShortCircuit::Any, key.span())?;
+        } else if lh.peek(kw::until) {
+            let key: kw::until = input.parse()?;
+            let _: syn::Token![=] = input.parse()?;
+            self.short_circuit.set(ShortCircuit::Until(input.parse()?), key.span())?;
+        } else if lh.peek(kw::collect) {
+            let key: kw::collect = input.parse()?;
+            self.gather.set(Gather::Collect, key.span())?;
+        } else if lh.peek(kw::chain) {
+            let key: kw::chain = input.parse()?;
+            self.gather.set(Gather::Chain, key.span())?;
+        } else if lh.peek(kw::order) {
+            let key: kw::order = input.parse()?;
+            let _: syn::Token![=] = input.parse()?;
+
+            let lh = input.lookahead1();
+            let order = if lh.peek(kw::reverse) {
+                let _: kw::reverse = input.parse()?;
+                Order::Reverse
+            } else if lh.peek(kw::priority) {
+                let _: kw::priority = input.parse()?;
+                Order::Priority
+            } else {
+                return Err(lh.error());
+            };
+            self.order.set(order, key.span())?;
+        } else {
+            return Err(lh.error());
+        }
+        Ok(())
+    }
+}
+
+/// The kind of type whose fields are delegated to.
+#[derive(Clone, Copy)]
+enum Container {
+    Struct,
+    /// The fields of one variant, which have to be matched refutably.
+    Enum,
+    /// The single field selected by the union tag.
+    Union,
+}
+
+/// The order in which fields are delegated to.
+enum Order {
+    /// Reverse declaration order.
+    Reverse,
+    /// Descending `priority` of fields, then declaration order.
+    Priority,
+}
+
+/// Strategies that combine all delegation results at once.
+enum Gather {
+    /// Collect the items of all returned collections into the return type.
+    Collect,
+    /// Chain the returned iterators.
+    Chain,
+}
+
+/// Strategies that stop delegating to later fields once the result is known.
+enum ShortCircuit {
+    FirstSome,
+    FirstOk,
+    FirstErr,
+    All,
+    Any,
+    Until(syn::Expr),
+}
+
+impl ShortCircuit {
+    /// Returns an expression that accepts `&T` and returns whether to stop delegating.
+    fn predicate(&self, span: Span) -> syn::Expr {
+        match self {
+            Self::FirstSome => syn::parse_quote_spanned!(span => ::core::option::Option::is_some),
+            Self::FirstOk => syn::parse_quote_spanned!(span => ::core::result::Result::is_ok),
+            Self::FirstErr => syn::parse_quote_spanned!(span => ::core::result::Result::is_err),
+            Self::All => syn::parse_quote_spanned!(span => |value: &bool| !*value),
+            Self::Any => syn::parse_quote_spanned!(span => |value: &bool| *value),
+            Self::Until(pred) => pred.clone(),
+        }
+    }
+}
+
+#[derive(Default)]
+struct UnionArgs {
+    union_tag:    util::Once<syn::Expr>,
+    unsafe_union: util::Once<()>,
+}
+
+impl util::ParseArgs for UnionArgs {
+    fn parse_once(&mut self, input: syn::parse::ParseStream) -> syn::Result<()> {
+        let lh = input.lookahead1();
+        if lh.peek(kw::union_tag) {
+            let key: kw::union_tag = input.parse()?;
+            let _: syn::Token![=] = input.parse()?;
+            self.union_tag.set(input.parse()?, key.span())?;
+        } else if lh.peek(kw::unsafe_union) {
+            let key: kw::unsafe_union = input.parse()?;
+            self.unsafe_union.set((), key.span())?;
+        } else {
+            return Err(lh.error());
+        }
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct FieldArgs {
+    tag:      util::Once<syn::Pat>,
+    priority: util::Once<i64>,
+}
+
+impl util::ParseArgs for FieldArgs {
+    fn parse_once(&mut self, input: syn::parse::ParseStream) -> syn::Result<()> {
+        let lh = input.lookahead1();
+        if lh.peek(kw::tag) {
+            let key: kw::tag = input.parse()?;
+            let _: syn::Token![=] = input.parse()?;
+            self.tag.set(syn::Pat::parse_multi(input)?, key.span())?;
+        } else if lh.peek(kw::priority) {
+            let key: kw::priority = input.parse()?;
+            let _: syn::Token![=] = input.parse()?;
+            let neg: Option<syn::Token![-]> = input.parse()?;
+            let lit: syn::LitInt = input.parse()?;
+            let priority: i64 = lit.base10_parse()?;
+            self.priority.set(if neg.is_some() { -priority } else { priority }, key.span())?;
+        } else {
             return Err(lh.error());
         }
         Ok(())
@@ -0,0 +1,163 @@
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::parse::{Parse, ParseStream};
+use syn::visit::{self, Visit};
+use syn::{Error, Result};
+
+use crate::{make, util};
+
+pub(crate) fn run(attr: TokenStream, item: TokenStream) -> Result<TokenStream> {
+    let Attr { vis, trait_token, trait_ident, make_args } = syn::parse2(attr)?;
+
+    let item: syn::ItemImpl = syn::parse2(item)?;
+
+    if let Some((_, trait_path, _)) = &item.trait_ {
+        return Err(Error::new_spanned(
+            trait_path,
+            "#[extract] can only be used on inherent impl blocks",
+        ));
+    }
+
+    let mut trait_items: Vec<syn::TraitItemFn> = Vec::new();
+    let mut impl_items: Vec<syn::ImplItemFn> = Vec::new();
+
+    for impl_item in &item.items {
+        let syn::ImplItem::Fn(impl_fn) = impl_item else { continue };
+        if matches!(impl_fn.vis, syn::Visibility::Inherited) {
+            continue;
+        }
+
+        check_impl_generics(&item.generics, &impl_fn.sig)?;
+
+        let mut sig = impl_fn.sig.clone();
+        let args = util::normalize_arg_pats(&mut sig);
+        let fn_ident = &sig.ident;
+        let turbofish = util::forward_turbofish(&sig);
+        let await_ = sig.asyncness.map(|_| quote!(.await));
+
+        let attrs: Vec<_> = impl_fn
+            .attrs
+            .iter()
+            .filter(|attr| ["doc", "cfg"].iter().any(|name| attr.path().is_ident(name)))
+            .collect();
+        let cfg_attrs: Vec<_> =
+            attrs.iter().copied().filter(|attr| attr.path().is_ident("cfg")).collect();
+
+        trait_items.push(syn::parse_quote! {
+            #(#attrs)*
+            #sig;
+        });
+        // inherent functions take precedence over trait functions in `Self::` paths
+        impl_items.push(syn::parse_quote! {
+            #(#cfg_attrs)*
+            #sig {
+                Self::#fn_ident #turbofish (#(#args),*) #await_
+            }
+        });
+    }
+
+    let item_trait = quote! {
+        #vis #trait_token #trait_ident {
+            #(#trait_items)*
+        }
+    };
+    let portrait = make::run(make_args, item_trait)?;
+
+    let (impl_generics, _, where_clause) = item.generics.split_for_impl();
+    let self_ty = &item.self_ty;
+
+    Ok(quote! {
+        #item
+
+        #portrait
+
+        impl #impl_generics #trait_ident for #self_ty #where_clause {
+            #(#impl_items)*
+        }
+    })
+}
+
+/// Rejects signatures that mention the generic parameters of the impl block,
+/// which are not in scope in the extracted trait.
+fn check_impl_generics(generics: &syn::Generics, sig: &syn::Signature) -> Result<()> {
+    struct Visitor<'t> {
+        generics: &'t syn::Generics,
+        found:    Option<Error>,
+    }
+
+    impl<'t> Visitor<'t> {
+        fn check_param(&mut self, path: &syn::Path, tokens: &dyn ToTokens) {
+            let first = &path.segments[0].ident;
+            let is_param = self.generics.type_params().any(|param| param.ident == *first)
+                || self.generics.const_params().any(|param| param.ident == *first);
+            if is_param {
+                self.found.get_or_insert_with(|| {
+                    Error::new_spanned(
+                        tokens,
+                        format_args!(
+                            "Cannot extract a signature that uses the generic parameter `{first}` \
+                             of the impl block"
+                        ),
+                    )
+                });
+            }
+        }
+    }
+
+    impl<'t, 'ast> Visit<'ast> for Visitor<'t> {
+        fn visit_type_path(&mut self, ty: &'ast syn::TypePath) {
+            if ty.qself.is_none() {
+                self.check_param(&ty.path, ty);
+            }
+            visit::visit_type_path(self, ty);
+        }
+
+        fn visit_expr_path(&mut self, expr: &'ast syn::ExprPath) {
+            if expr.qself.is_none() {
+                self.check_param(&expr.path, expr);
+            }
+            visit::visit_expr_path(self, expr);
+        }
+
+        fn visit_lifetime(&mut self, lifetime: &'ast syn::Lifetime) {
+            if self.generics.lifetimes().any(|param| param.lifetime == *lifetime) {
+                self.found.get_or_insert_with(|| {
+                    Error::new_spanned(
+                        lifetime,
+                        format_args!(
+                            "Cannot extract a signature that uses the lifetime `{lifetime}` of \
+                             the impl block"
+                        ),
+                    )
+                });
+            }
+        }
+    }
+
+    let mut visitor = Visitor { generics, found: None };
+    visitor.visit_signature(sig);
+    visitor.found.map_or(Ok(()), Err)
+}
+
+struct Attr {
+    vis:         syn::Visibility,
+    trait_token: syn::Token![trait],
+    trait_ident: syn::Ident,
+    make_args:   TokenStream,
+}
+
+impl Parse for Attr {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let vis = input.parse()?;
+        let trait_token = input.parse()?;
+        let trait_ident = input.parse()?;
+
+        let mut make_args = TokenStream::new();
+        if !input.is_empty() {
+            let _: syn::Token![,] = input.parse()?;
+            make_args = input.parse()?;
+        }
+
+        Ok(Self { vis, trait_token, trait_ident, make_args })
+    }
+}
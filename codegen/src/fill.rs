@@ -4,6 +4,8 @@ use quote::{format_ident, quote};
 use syn::parse::{Parse, ParseStream};
 use syn::{Error, Result};
 
+use crate::util;
+
 pub(crate) fn run(attr: TokenStream, item: TokenStream) -> Result<TokenStream> {
     let Attr { debug_print, debug_print_filler_output, mod_path, attr_path, args: attr_args } =
         syn::parse2(attr)?;
@@ -32,12 +34,14 @@ pub(crate) fn run(attr: TokenStream, item: TokenStream) -> Result<TokenStream> {
         mod_path
     });
 
+    let protocol_version = util::protocol_version();
+
     let output = quote! {
         const _: () = {
-            use #mod_path::imports::*;
-
             #target! {
                 @TARGET {#attr_path}
+                @IMPORTS { use #mod_path::imports::*; }
+                @FILL_PROTOCOL {#protocol_version}
                 @ARGS {#attr_args}
                 @IMPL {#item}
                 @DEBUG_PRINT_FILLER_OUTPUT {#debug_print_filler_output}
@@ -0,0 +1,277 @@
+use std::collections::HashSet;
+
+use heck::ToSnakeCase;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::visit_mut::VisitMut;
+use syn::{Error, Result};
+
+use crate::util;
+
+pub(crate) fn run(attr: TokenStream, item: TokenStream) -> Result<TokenStream> {
+    let Attr { debug_print, debug_print_filler_output, mod_path, exclude, vis, trait_path } =
+        syn::parse2(attr)?;
+
+    let item: syn::ItemImpl = syn::parse2(item)?;
+
+    if let Some((_, trait_path, _)) = &item.trait_ {
+        return Err(Error::new_spanned(
+            trait_path,
+            "#[fill_inherent] can only be used on inherent impl blocks",
+        ));
+    }
+
+    let mod_path = mod_path.unwrap_or_else(|| {
+        // deduce the path to the portrait imports module based on the trait path
+        let mut mod_path = trait_path.clone();
+        let mod_name = mod_path.segments.last_mut().expect("path segments should be nonempty");
+        mod_name.ident = format_ident!("{}_portrait", mod_name.ident.to_string().to_snake_case());
+        mod_name.arguments = syn::PathArguments::None;
+        mod_path
+    });
+
+    let mut trait_macro = trait_path.clone();
+    trait_macro.segments.last_mut().expect("path segments should be nonempty").arguments =
+        syn::PathArguments::None;
+
+    let protocol_version = util::protocol_version();
+
+    let output = quote! {
+        const _: () = {
+            #trait_macro! {
+                @TARGET {::portrait::__fill_inherent_filler}
+                @IMPORTS { use #mod_path::imports::*; }
+                @FILL_PROTOCOL {#protocol_version}
+                @ARGS {#vis #trait_path; #exclude}
+                @IMPL {#item}
+                @DEBUG_PRINT_FILLER_OUTPUT {#debug_print_filler_output}
+            }
+        };
+    };
+
+    if debug_print {
+        println!("{output}");
+    }
+
+    Ok(output)
+}
+
+mod kw {
+    syn::custom_keyword!(MOD_PATH);
+    syn::custom_keyword!(EXCLUDE);
+    syn::custom_keyword!(__DEBUG_PRINT);
+    syn::custom_keyword!(DEBUG_PRINT_FILLER_OUTPUT);
+}
+
+struct Attr {
+    debug_print:               bool,
+    debug_print_filler_output: bool,
+    mod_path:                  Option<syn::Path>,
+    exclude:                   Punctuated<syn::Ident, syn::Token![,]>,
+    vis:                       syn::Visibility,
+    trait_path:                syn::Path,
+}
+
+impl Parse for Attr {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut debug_print = false;
+        let mut debug_print_filler_output = false;
+        let mut mod_path = None;
+        let mut exclude = Punctuated::new();
+
+        while input.peek(syn::Token![@]) {
+            input.parse::<syn::Token![@]>().expect("peek result");
+
+            let lh = input.lookahead1();
+            if lh.peek(kw::__DEBUG_PRINT) {
+                input.parse::<kw::__DEBUG_PRINT>().expect("peek result");
+
+                debug_print = true;
+            } else if lh.peek(kw::DEBUG_PRINT_FILLER_OUTPUT) {
+                input.parse::<kw::DEBUG_PRINT_FILLER_OUTPUT>().expect("peek result");
+
+                debug_print_filler_output = true;
+            } else if lh.peek(kw::MOD_PATH) {
+                input.parse::<kw::MOD_PATH>().expect("peek result");
+
+                let inner;
+                syn::parenthesized!(inner in input);
+                mod_path = Some(inner.parse()?);
+            } else if lh.peek(kw::EXCLUDE) {
+                input.parse::<kw::EXCLUDE>().expect("peek result");
+
+                let inner;
+                syn::parenthesized!(inner in input);
+                exclude.extend(Punctuated::<syn::Ident, syn::Token![,]>::parse_terminated(&inner)?);
+            } else {
+                return Err(lh.error());
+            }
+        }
+
+        let vis = input.parse()?;
+        let trait_path = input.parse()?;
+
+        Ok(Self { debug_print, debug_print_filler_output, mod_path, exclude, vis, trait_path })
+    }
+}
+
+/// The filler invoked by the portrait macro with the arguments from [`run`].
+pub(crate) struct Filler;
+
+pub(crate) struct FillerArgs {
+    vis:        syn::Visibility,
+    trait_path: syn::Path,
+    exclude:    Punctuated<syn::Ident, syn::Token![,]>,
+}
+
+impl Parse for FillerArgs {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let vis = input.parse()?;
+        let trait_path = input.parse()?;
+        let _: syn::Token![;] = input.parse()?;
+        let exclude = Punctuated::parse_terminated(input)?;
+        Ok(Self { vis, trait_path, exclude })
+    }
+}
+
+impl portrait_framework::FillImpl for Filler {
+    type Args = FillerArgs;
+
+    fn fill(
+        self,
+        portrait: &[syn::TraitItem],
+        FillerArgs { vis, trait_path, exclude }: FillerArgs,
+        item_impl: &syn::ItemImpl,
+    ) -> Result<TokenStream> {
+        let trait_item_names: HashSet<_> = portrait.iter().filter_map(trait_item_ident).collect();
+        for ident in &exclude {
+            if !trait_item_names.contains(ident) {
+                return Err(Error::new_spanned(
+                    ident,
+                    format_args!("The trait has no associated item called `{ident}`"),
+                ));
+            }
+        }
+
+        let assoc_types: HashSet<_> = portrait
+            .iter()
+            .filter_map(|item| match item {
+                syn::TraitItem::Type(item) => Some(&item.ident),
+                _ => None,
+            })
+            .collect();
+
+        let skipped: HashSet<_> =
+            exclude.iter().chain(item_impl.items.iter().filter_map(impl_item_ident)).collect();
+
+        let mut items = item_impl.items.clone();
+        for trait_item in portrait {
+            if trait_item_ident(trait_item).is_some_and(|ident| skipped.contains(ident)) {
+                continue;
+            }
+
+            match trait_item {
+                syn::TraitItem::Const(item) => {
+                    let attrs = forwarded_attrs(&item.attrs);
+                    let ident = &item.ident;
+                    let mut ty = item.ty.clone();
+                    QualifySelf { trait_path: &trait_path, assoc_types: &assoc_types }
+                        .visit_type_mut(&mut ty);
+                    items.push(syn::parse_quote! {
+                        #(#attrs)*
+                        #vis const #ident: #ty = <Self as #trait_path>::#ident;
+                    });
+                }
+                syn::TraitItem::Fn(item) => {
+                    items.push(syn::ImplItem::Fn(forward_fn(
+                        item,
+                        &vis,
+                        &trait_path,
+                        &assoc_types,
+                    )));
+                }
+                // inherent associated types are unstable, so they are only accessible through the trait
+                _ => {}
+            }
+        }
+
+        let item_impl = syn::ItemImpl { items, ..item_impl.clone() };
+        Ok(quote!(#item_impl))
+    }
+}
+
+fn forward_fn(
+    item: &syn::TraitItemFn,
+    vis: &syn::Visibility,
+    trait_path: &syn::Path,
+    assoc_types: &HashSet<&syn::Ident>,
+) -> syn::ImplItemFn {
+    let mut sig = item.sig.clone();
+    QualifySelf { trait_path, assoc_types }.visit_signature_mut(&mut sig);
+
+    let args = util::normalize_arg_pats(&mut sig);
+    let fn_ident = &sig.ident;
+    let turbofish = util::forward_turbofish(&sig);
+    let await_ = sig.asyncness.map(|_| quote!(.await));
+
+    let attrs = forwarded_attrs(&item.attrs);
+    syn::parse_quote! {
+        #(#attrs)*
+        #vis #sig {
+            <Self as #trait_path>::#fn_ident #turbofish (#(#args),*) #await_
+        }
+    }
+}
+
+/// Copies the attributes that still make sense on the inherent item.
+fn forwarded_attrs(attrs: &[syn::Attribute]) -> Vec<&syn::Attribute> {
+    attrs
+        .iter()
+        .filter(|attr| {
+            ["doc", "cfg", "must_use", "deprecated"].iter().any(|name| attr.path().is_ident(name))
+        })
+        .collect()
+}
+
+/// Rewrites `Self::Assoc` to `<Self as Trait>::Assoc`,
+/// since associated types of the trait are ambiguous in inherent impls.
+struct QualifySelf<'t> {
+    trait_path:  &'t syn::Path,
+    assoc_types: &'t HashSet<&'t syn::Ident>,
+}
+
+impl VisitMut for QualifySelf<'_> {
+    fn visit_type_path_mut(&mut self, ty: &mut syn::TypePath) {
+        if ty.qself.is_none()
+            && ty.path.segments.len() >= 2
+            && ty.path.segments[0].ident == "Self"
+            && self.assoc_types.contains(&ty.path.segments[1].ident)
+        {
+            let trait_path = self.trait_path;
+            let rest: Vec<_> = ty.path.segments.iter().skip(1).collect();
+            *ty = syn::parse_quote!(<Self as #trait_path>::#(#rest)::*);
+        }
+
+        syn::visit_mut::visit_type_path_mut(self, ty);
+    }
+}
+
+fn trait_item_ident(item: &syn::TraitItem) -> Option<&syn::Ident> {
+    match item {
+        syn::TraitItem::Const(item) => Some(&item.ident),
+        syn::TraitItem::Fn(item) => Some(&item.sig.ident),
+        syn::TraitItem::Type(item) => Some(&item.ident),
+        _ => None,
+    }
+}
+
+fn impl_item_ident(item: &syn::ImplItem) -> Option<&syn::Ident> {
+    match item {
+        syn::ImplItem::Const(item) => Some(&item.ident),
+        syn::ImplItem::Fn(item) => Some(&item.sig.ident),
+        syn::ImplItem::Type(item) => Some(&item.ident),
+        _ => None,
+    }
+}
@@ -1,15 +1,32 @@
-use proc_macro2::Span;
-use quote::quote;
+use portrait_framework::ReturnKind;
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::parse::ParseStream;
 use syn::spanned::Spanned;
 use syn::{Error, Result};
 
-pub(crate) struct Generator(pub(crate) portrait_framework::NoArgs);
+use crate::util::{self, Args, ItemOverrides, Once, ParseArgs};
+
+pub(crate) struct Generator(pub(crate) Args<Arg>);
 impl portrait_framework::GenerateImpl for Generator {
     fn generate_const(
         &mut self,
-        _: portrait_framework::ImplContext,
+        ctx: portrait_framework::ImplContext,
         item: &syn::TraitItemConst,
     ) -> Result<syn::ImplItemConst> {
+        let Args(arg) = &self.0;
+        arg.overrides.check_names(ctx.all_trait_items)?;
+        let mut expr = arg.value_for(&item.ident, Some(&item.ty));
+        if let (None, Some((_, path))) = (&expr, &arg.const_default.0) {
+            let ty = &item.ty;
+            let path = match path {
+                Some(path) => quote!(#path),
+                None => quote!(::portrait::ConstDefault),
+            };
+            expr = Some(quote!(<#ty as #path>::DEFAULT));
+        }
+        let expr = expr.unwrap_or_else(|| quote!(Default::default()));
+
         Ok(syn::ImplItemConst {
             attrs:       item
                 .attrs
@@ -25,17 +42,74 @@ impl portrait_framework::GenerateImpl for Generator {
             colon_token: item.colon_token,
             ty:          item.ty.clone(),
             eq_token:    syn::Token![=](item.span()),
-            expr:        syn::parse_quote!(Default::default()),
+            expr:        syn::parse_quote!(#expr),
             semi_token:  item.semi_token,
         })
     }
 
     fn generate_fn(
         &mut self,
-        _: portrait_framework::ImplContext,
+        ctx: portrait_framework::ImplContext,
         item: &syn::TraitItemFn,
     ) -> Result<syn::ImplItemFn> {
+        let Args(arg) = &self.0;
+        arg.overrides.check_names(ctx.all_trait_items)?;
+
+        let block = match ReturnKind::of(&item.sig) {
+            kind @ ReturnKind::ImplTrait(impl_trait) if kind.is_future() => {
+                let expr = arg.value_or_default(&item.sig.ident, future_output(impl_trait));
+                syn::parse_quote! {
+                    { async { #expr } }
+                }
+            }
+            ReturnKind::ImplTrait(impl_trait) => {
+                return Err(Error::new_spanned(
+                    impl_trait,
+                    "portrait::default cannot implement return-position `impl Trait` other than \
+                     `impl Future`",
+                ))
+            }
+            _ => {
+                let output = match &item.sig.output {
+                    syn::ReturnType::Default => None,
+                    syn::ReturnType::Type(_, ty) => Some(&**ty),
+                };
+                let expr = arg.value_or_default(&item.sig.ident, output);
+                syn::parse_quote! {
+                    { #expr }
+                }
+            }
+        };
+
         Ok(syn::ImplItemFn {
+            attrs: item.attrs.iter().filter(|attr| attr.path().is_ident("cfg")).cloned().collect(),
+            vis: syn::Visibility::Inherited,
+            defaultness: None,
+            sig: util::unuse_sig(item.sig.clone()),
+            block,
+        })
+    }
+
+    fn generate_type(
+        &mut self,
+        ctx: portrait_framework::ImplContext,
+        item: &syn::TraitItemType,
+    ) -> Result<syn::ImplItemType> {
+        let Args(arg) = &self.0;
+        arg.overrides.check_names(ctx.all_trait_items)?;
+
+        let Some(ty) = arg.overrides.ty(&item.ident) else {
+            return Err(Error::new_spanned(
+                item,
+                format!(
+                    "portrait::default cannot implement associated types automatically, specify \
+                     one with `portrait::default(types({} = ...))`",
+                    item.ident
+                ),
+            ));
+        };
+
+        Ok(syn::ImplItemType {
             attrs:       item
                 .attrs
                 .iter()
@@ -44,39 +118,95 @@ impl portrait_framework::GenerateImpl for Generator {
                 .collect(),
             vis:         syn::Visibility::Inherited,
             defaultness: None,
-            sig:         unuse_sig(item.sig.clone()),
-            block:       syn::parse_quote! {
-                { Default::default() }
-            },
+            type_token:  item.type_token,
+            ident:       item.ident.clone(),
+            generics:    item.generics.clone(),
+            eq_token:    syn::Token![=](item.span()),
+            ty:          ty.clone(),
+            semi_token:  item.semi_token,
         })
     }
+}
 
-    fn generate_type(
-        &mut self,
-        _: portrait_framework::ImplContext,
-        item: &syn::TraitItemType,
-    ) -> Result<syn::ImplItemType> {
-        Err(Error::new_spanned(
-            item,
-            "portrait::default cannot implement associated types automatically",
-        ))
+#[derive(Default)]
+pub(crate) struct Arg {
+    /// Overrides for named constants, functions and types.
+    overrides:     ItemOverrides,
+    /// `for Ty = expr` rules for constants and function return values of type `Ty`.
+    ty_rules:      Vec<(syn::Type, syn::Expr)>,
+    /// Whether constants use `ConstDefault` instead of `Default`,
+    /// optionally with the path to a trait other than `::portrait::ConstDefault`.
+    const_default: Once<Option<syn::Path>>,
+}
+
+mod kw {
+    syn::custom_keyword!(const_default);
+}
+
+impl ParseArgs for Arg {
+    fn parse_once(&mut self, input: ParseStream) -> Result<()> {
+        if input.peek(syn::Token![for]) {
+            _ = input.parse::<syn::Token![for]>()?;
+            let ty: syn::Type = input.parse()?;
+            _ = input.parse::<syn::Token![=]>()?;
+            let expr = input.parse()?;
+
+            if self.ty_rules.iter().any(|(other, _)| same_type(other, &ty)) {
+                return Err(Error::new_spanned(ty, "duplicate default for this type"));
+            }
+            self.ty_rules.push((ty, expr));
+        } else if input.peek(kw::const_default) && !input.peek2(syn::Token![=]) {
+            let key = input.parse::<kw::const_default>()?;
+            let path = if input.peek(syn::token::Paren) {
+                let inner;
+                syn::parenthesized!(inner in input);
+                Some(inner.parse()?)
+            } else {
+                None
+            };
+            self.const_default.set(path, key.span())?;
+        } else {
+            self.overrides.parse_once(input)?;
+        }
+
+        Ok(())
     }
 }
 
-fn unuse_sig(mut sig: syn::Signature) -> syn::Signature {
-    for input in &mut sig.inputs {
-        if let syn::FnArg::Typed(typed) = input {
-            typed.attrs.push(syn::Attribute {
-                pound_token:   syn::Token![#](Span::call_site()),
-                style:         syn::AttrStyle::Outer,
-                bracket_token: syn::token::Bracket(Span::call_site()),
-                meta:          syn::Meta::List(syn::MetaList {
-                    path:      syn::parse_quote!(allow),
-                    delimiter: syn::MacroDelimiter::Paren(syn::token::Paren(typed.span())),
-                    tokens:    quote!(unused_variables),
-                }),
-            });
+impl Arg {
+    /// Returns the configured expression for the constant or function `ident` of type `ty`.
+    ///
+    /// Named overrides take precedence over type rules.
+    fn value_for(&self, ident: &syn::Ident, ty: Option<&syn::Type>) -> Option<TokenStream> {
+        if let Some(expr) = self.overrides.value(ident) {
+            return Some(expr.to_token_stream());
         }
+
+        let ty = ty?;
+        let (_, expr) = self.ty_rules.iter().find(|(other, _)| same_type(other, ty))?;
+        Some(expr.to_token_stream())
     }
-    sig
+
+    /// Like [`Self::value_for`], but falls back to `Default::default()`.
+    fn value_or_default(&self, ident: &syn::Ident, ty: Option<&syn::Type>) -> TokenStream {
+        self.value_for(ident, ty).unwrap_or_else(|| quote!(Default::default()))
+    }
+}
+
+/// Returns the `Output` type of an `impl Future<Output = T>` return type.
+fn future_output(impl_trait: &syn::TypeImplTrait) -> Option<&syn::Type> {
+    impl_trait.bounds.iter().find_map(|bound| {
+        let syn::TypeParamBound::Trait(bound) = bound else { return None };
+        let segment = bound.path.segments.last().filter(|segment| segment.ident == "Future")?;
+        let syn::PathArguments::AngleBracketed(args) = &segment.arguments else { return None };
+        args.args.iter().find_map(|arg| match arg {
+            syn::GenericArgument::AssocType(assoc) if assoc.ident == "Output" => Some(&assoc.ty),
+            _ => None,
+        })
+    })
+}
+
+/// Compares types by their tokens, which is sufficient for the types written in the trait.
+fn same_type(a: &syn::Type, b: &syn::Type) -> bool {
+    a.to_token_stream().to_string() == b.to_token_stream().to_string()
 }
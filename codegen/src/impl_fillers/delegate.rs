@@ -1,7 +1,7 @@
 use std::iter;
 
 use proc_macro2::Span;
-use quote::{quote, ToTokens};
+use quote::{quote, quote_spanned, ToTokens};
 use syn::parse::{Parse, ParseStream};
 use syn::spanned::Spanned;
 
@@ -38,7 +38,7 @@ impl portrait_framework::GenerateImpl for Generator {
         ctx: portrait_framework::ImplContext,
         item: &syn::TraitItemFn,
     ) -> syn::Result<syn::ImplItemFn> {
-        let Arg { ty: delegate_ty, value: delegate_value } = &self.0;
+        let Arg { ty: delegate_ty, value: delegate_value, map_err } = &self.0;
         let trait_path = &ctx.impl_block.trait_.as_ref().expect("checked in framework").1;
 
         let mut sig = item.sig.clone();
@@ -111,6 +111,17 @@ impl portrait_framework::GenerateImpl for Generator {
             })
             .collect::<syn::Result<Vec<_>>>()?;
 
+        let await_ = sig.asyncness.map(|asyncness| quote_spanned!(asyncness.span() => .await));
+
+        let mut body = quote! {
+            <#delegate_ty as #trait_path>::#sig_ident(#(#args,)*) #await_
+        };
+        if let (Some(map_err), syn::ReturnType::Type(_, output)) = (map_err, &sig.output) {
+            if let Some(shape) = ResultShape::of(output, ctx.impl_block) {
+                body = shape.map_err(body, &map_err.expr);
+            }
+        }
+
         let inline_attr = syn::Attribute {
             pound_token:   syn::Token![#](Span::call_site()),
             style:         syn::AttrStyle::Outer,
@@ -129,9 +140,7 @@ impl portrait_framework::GenerateImpl for Generator {
             vis: syn::Visibility::Inherited,
             defaultness: None,
             sig,
-            block: syn::parse_quote! {{
-                <#delegate_ty as #trait_path>::#sig_ident(#(#args,)*)
-            }},
+            block: syn::parse_quote! {{ #body }},
         })
     }
 
@@ -168,28 +177,184 @@ impl portrait_framework::GenerateImpl for Generator {
             semi_token: item.semi_token,
         })
     }
+
+    fn finish(&mut self, ctx: portrait_framework::ImplContext) -> syn::Result<()> {
+        let Some(map_err) = &self.0.map_err else { return Ok(()) };
+
+        let maps_any = ctx.all_trait_items.iter().any(|item| {
+            let syn::TraitItem::Fn(item) = item else { return false };
+            let provided = ctx.impl_block.items.iter().any(
+                |impl_item| matches!(impl_item, syn::ImplItem::Fn(impl_item) if impl_item.sig.ident == item.sig.ident),
+            );
+            match &item.sig.output {
+                syn::ReturnType::Type(_, output) if !provided => {
+                    ResultShape::of(output, ctx.impl_block).is_some()
+                }
+                _ => false,
+            }
+        });
+        if !maps_any {
+            return Err(syn::Error::new(
+                map_err.map_err_token.span,
+                "`map_err` does not apply to any generated function; it only converts `Result<T, \
+                 Self::X>`, `Poll<Result<T, Self::X>>` and `Option<Result<T, Self::X>>` where `X` \
+                 is an associated type specified in the impl block",
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// The position of an error type in a return type that `map_err` converts.
+enum ResultShape {
+    /// `Result<T, Self::Error>`
+    Result,
+    /// `Poll<Result<T, Self::Error>>`
+    Poll,
+    /// `Option<Result<T, Self::Error>>`
+    Option,
+}
+
+impl ResultShape {
+    /// Matches a return type whose error type is an associated type
+    /// that the user has provided in the impl block,
+    /// i.e. one that differs from the associated type of the delegate.
+    fn of(ty: &syn::Type, impl_block: &syn::ItemImpl) -> Option<Self> {
+        let (ident, arg) = single_generic_path(ty)?;
+        match ident.to_string().as_str() {
+            "Result" => {
+                let mut args = arg.args.iter();
+                let (Some(_), Some(syn::GenericArgument::Type(err)), None) =
+                    (args.next(), args.next(), args.next())
+                else {
+                    return None;
+                };
+                is_provided_assoc_type(err, impl_block).then_some(Self::Result)
+            }
+            "Poll" | "Option" => {
+                let Some(syn::GenericArgument::Type(inner)) = arg.args.first() else {
+                    return None;
+                };
+                let (inner_ident, _) = single_generic_path(inner)?;
+                if inner_ident != "Result" {
+                    return None;
+                }
+                Self::of(inner, impl_block)?;
+                Some(if ident == "Poll" { Self::Poll } else { Self::Option })
+            }
+            _ => None,
+        }
+    }
+
+    fn map_err(
+        &self,
+        value: proc_macro2::TokenStream,
+        map_err: &syn::Expr,
+    ) -> proc_macro2::TokenStream {
+        match self {
+            Self::Result => quote!(::core::result::Result::map_err(#value, #map_err)),
+            Self::Poll => quote! {
+                ::core::task::Poll::<::core::result::Result<_, _>>::map_err(#value, #map_err)
+            },
+            Self::Option => quote! {
+                ::core::option::Option::map(#value, |result| ::core::result::Result::map_err(result, #map_err))
+            },
+        }
+    }
+}
+
+/// Returns the last segment identifier and its angle-bracketed arguments of a path type.
+fn single_generic_path(
+    ty: &syn::Type,
+) -> Option<(&syn::Ident, &syn::AngleBracketedGenericArguments)> {
+    let syn::Type::Path(ty) = ty else { return None };
+    if ty.qself.is_some() {
+        return None;
+    }
+    let segment = ty.path.segments.last()?;
+    let syn::PathArguments::AngleBracketed(args) = &segment.arguments else { return None };
+    Some((&segment.ident, args))
+}
+
+/// Whether the type is `Self::Ident` or `<Self as Trait>::Ident`
+/// where `Ident` is an associated type specified in the impl block.
+fn is_provided_assoc_type(ty: &syn::Type, impl_block: &syn::ItemImpl) -> bool {
+    let syn::Type::Path(ty) = ty else { return false };
+    let ident = match &ty.qself {
+        None => {
+            let mut segments = ty.path.segments.iter();
+            match (segments.next(), segments.next(), segments.next()) {
+                (Some(self_), Some(assoc), None) if self_.ident == "Self" => &assoc.ident,
+                _ => return false,
+            }
+        }
+        Some(qself) => {
+            if !portrait_framework::is_self(&qself.ty) {
+                return false;
+            }
+            match ty.path.segments.last() {
+                Some(assoc) => &assoc.ident,
+                None => return false,
+            }
+        }
+    };
+
+    impl_block
+        .items
+        .iter()
+        .any(|item| matches!(item, syn::ImplItem::Type(item) if item.ident == *ident))
 }
 
 pub(crate) struct Arg {
-    ty:    syn::Type,
-    value: Option<ArgValue>,
+    ty:      syn::Type,
+    value:   Option<ArgValue>,
+    map_err: Option<ArgMapErr>,
 }
 struct ArgValue {
     _semi_token: syn::Token![;],
     expr:        syn::Expr,
 }
+struct ArgMapErr {
+    _semi_token:   syn::Token![;],
+    map_err_token: kw::map_err,
+    _eq_token:     syn::Token![=],
+    expr:          syn::Expr,
+}
+
+mod kw {
+    syn::custom_keyword!(map_err);
+}
 
 impl Parse for Arg {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let ty = input.parse()?;
-        let value = if input.peek(syn::Token![;]) {
+        let value = if input.peek(syn::Token![;]) && !peek_map_err(input) {
             let semi_token = input.parse().expect("peeked");
             let expr = input.parse()?;
             Some(ArgValue { _semi_token: semi_token, expr })
         } else {
             None
         };
+        let map_err = if input.peek(syn::Token![;]) {
+            Some(ArgMapErr {
+                _semi_token:   input.parse()?,
+                map_err_token: input.parse()?,
+                _eq_token:     input.parse()?,
+                expr:          input.parse()?,
+            })
+        } else {
+            None
+        };
+
+        Ok(Self { ty, value, map_err })
+    }
+}
 
-        Ok(Self { ty, value })
+fn peek_map_err(input: ParseStream) -> bool {
+    input.peek2(kw::map_err) && {
+        let fork = input.fork();
+        let _: syn::Token![;] = fork.parse().expect("peeked");
+        let _: kw::map_err = fork.parse().expect("peeked");
+        fork.peek(syn::Token![=])
     }
 }
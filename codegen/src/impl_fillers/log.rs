@@ -1,4 +1,5 @@
 use itertools::Itertools;
+use portrait_framework::ReturnKind;
 use proc_macro2::{Span, TokenStream};
 use quote::quote;
 use syn::parse::{Parse, ParseStream};
@@ -63,6 +64,22 @@ impl portrait_framework::GenerateImpl for Generator {
         }
 
         let fmt_string = format!("{}({})", &sig.ident, fmt_args.iter().map(|_| "{:?}").join(", "));
+        let mut log_expr = quote!(#logger!(#prefix_args #fmt_string, #(#fmt_args),*));
+
+        match ReturnKind::of(&sig) {
+            // log when the future is polled, consistent with the body of an `async fn`
+            kind @ ReturnKind::ImplTrait(_) if kind.is_future() => {
+                log_expr = quote!(async move { #log_expr });
+            }
+            ReturnKind::ImplTrait(impl_trait) => {
+                return Err(Error::new_spanned(
+                    impl_trait,
+                    "portrait::log cannot implement return-position `impl Trait` other than `impl \
+                     Future`",
+                ))
+            }
+            _ => {}
+        }
 
         Ok(syn::ImplItemFn {
             attrs: item.attrs.iter().filter(|attr| attr.path().is_ident("cfg")).cloned().collect(),
@@ -70,7 +87,7 @@ impl portrait_framework::GenerateImpl for Generator {
             defaultness: None,
             sig,
             block: syn::parse_quote! {{
-                #logger!(#prefix_args #fmt_string, #(#fmt_args),*)
+                #log_expr
             }},
         })
     }
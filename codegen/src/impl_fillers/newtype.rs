@@ -0,0 +1,302 @@
+use std::iter;
+
+use proc_macro2::{Span, TokenStream, TokenTree};
+use quote::{quote, ToTokens};
+use syn::parse::{Parse, ParseStream};
+use syn::spanned::Spanned;
+
+use crate::util;
+
+pub(crate) struct Generator(pub(crate) Arg);
+impl portrait_framework::GenerateImpl for Generator {
+    fn generate_const(
+        &mut self,
+        ctx: portrait_framework::ImplContext,
+        item: &syn::TraitItemConst,
+    ) -> syn::Result<syn::ImplItemConst> {
+        let Arg { ty: inner_ty, .. } = &self.0;
+        let trait_path = &ctx.impl_block.trait_.as_ref().expect("checked in framework").1;
+        let item_ident = &item.ident;
+
+        let mut expr = quote!(<#inner_ty as #trait_path>::#item_ident);
+        if portrait_framework::is_self(&item.ty) {
+            expr = self.0.construct(expr);
+        } else if portrait_framework::contains_self(&item.ty) {
+            return Err(syn::Error::new_spanned(
+                &item.ty,
+                "newtype cannot convert constants of this type, implement it manually",
+            ));
+        }
+
+        let attrs = item.attrs.iter().filter(|attr| attr.path().is_ident("cfg"));
+        let const_token = item.const_token;
+        let ty = &item.ty;
+        Ok(syn::parse_quote! {
+            #(#attrs)*
+            #const_token #item_ident: #ty = #expr;
+        })
+    }
+
+    fn generate_fn(
+        &mut self,
+        ctx: portrait_framework::ImplContext,
+        item: &syn::TraitItemFn,
+    ) -> syn::Result<syn::ImplItemFn> {
+        let Arg { ty: inner_ty, accessor, .. } = &self.0;
+        let trait_path = &ctx.impl_block.trait_.as_ref().expect("checked in framework").1;
+
+        let mut sig = item.sig.clone();
+        // `self` in the signature must have the same hygiene as `self` in the accessor
+        util::set_sig_arg_span(&mut sig, accessor.span())?;
+        let arg_exprs = util::normalize_arg_pats(&mut sig);
+
+        let args = sig
+            .inputs
+            .iter()
+            .zip(arg_exprs)
+            .map(|(input, expr)| match input {
+                syn::FnArg::Receiver(receiver) => {
+                    let ty = &receiver.ty;
+                    self.0.unwrap(ty, expr.into_token_stream()).ok_or_else(|| {
+                        syn::Error::new_spanned(receiver, "newtype cannot convert this receiver")
+                    })
+                }
+                syn::FnArg::Typed(pat_ty) => {
+                    let ty = &pat_ty.ty;
+                    self.0.unwrap(ty, expr.into_token_stream()).ok_or_else(|| {
+                        syn::Error::new_spanned(
+                            ty,
+                            "newtype cannot convert parameters of this type, implement the \
+                             function manually",
+                        )
+                    })
+                }
+            })
+            .collect::<syn::Result<Vec<_>>>()?;
+
+        let fn_ident = &sig.ident;
+        let turbofish = util::forward_turbofish(&sig);
+        let await_ = sig.asyncness.map(|_| quote!(.await));
+        let call = quote!(<#inner_ty as #trait_path>::#fn_ident #turbofish (#(#args),*) #await_);
+
+        let body = match &sig.output {
+            syn::ReturnType::Default => call,
+            syn::ReturnType::Type(_, ty) => self.0.wrap(ty, call).ok_or_else(|| {
+                syn::Error::new_spanned(
+                    ty,
+                    "newtype cannot convert return values of this type, implement the function \
+                     manually",
+                )
+            })?,
+        };
+
+        let inline_attr = syn::Attribute {
+            pound_token:   syn::Token![#](Span::call_site()),
+            style:         syn::AttrStyle::Outer,
+            bracket_token: syn::token::Bracket(Span::call_site()),
+            meta:          syn::Meta::Path(syn::parse_quote!(inline)),
+        };
+
+        Ok(syn::ImplItemFn {
+            attrs: item
+                .attrs
+                .iter()
+                .filter(|attr| attr.path().is_ident("cfg"))
+                .cloned()
+                .chain(iter::once(inline_attr))
+                .collect(),
+            vis: syn::Visibility::Inherited,
+            defaultness: None,
+            sig,
+            block: syn::parse_quote!({ #body }),
+        })
+    }
+
+    fn generate_type(
+        &mut self,
+        ctx: portrait_framework::ImplContext,
+        item: &syn::TraitItemType,
+    ) -> syn::Result<syn::ImplItemType> {
+        let Arg { ty: inner_ty, .. } = &self.0;
+        let trait_path = &ctx.impl_block.trait_.as_ref().expect("checked in framework").1;
+        let item_ident = &item.ident;
+        let (_, generics_unbound, _) = item.generics.split_for_impl();
+        let attrs = item.attrs.iter().filter(|attr| attr.path().is_ident("cfg"));
+        let generics = &item.generics;
+        let where_clause = &item.generics.where_clause;
+        Ok(syn::parse_quote! {
+            #(#attrs)*
+            type #item_ident #generics = <#inner_ty as #trait_path>::#item_ident #generics_unbound
+                #where_clause;
+        })
+    }
+}
+
+pub(crate) struct Arg {
+    ty:       syn::Type,
+    accessor: syn::Expr,
+    ctor:     Option<syn::Expr>,
+}
+
+impl Parse for Arg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ty = input.parse()?;
+        let _: syn::Token![;] = input.parse()?;
+        let accessor = input.parse()?;
+
+        let ctor = if input.peek(syn::Token![;]) {
+            let _: syn::Token![;] = input.parse()?;
+            Some(input.parse()?)
+        } else {
+            None
+        };
+
+        Ok(Self { ty, accessor, ctor })
+    }
+}
+
+impl Arg {
+    /// Converts a value of type `ty` mentioning `Self` to the corresponding inner type,
+    /// or returns `None` if the type is not supported.
+    fn unwrap(&self, ty: &syn::Type, value: TokenStream) -> Option<TokenStream> {
+        if !portrait_framework::contains_self(ty) {
+            return Some(value);
+        }
+
+        match ty {
+            syn::Type::Paren(ty) => self.unwrap(&ty.elem, value),
+            syn::Type::Group(ty) => self.unwrap(&ty.elem, value),
+            _ if portrait_framework::is_self(ty) => Some(self.access(value)),
+            syn::Type::Reference(ty) if portrait_framework::is_self(&ty.elem) => {
+                let and = ty.and_token;
+                let mut_ = ty.mutability;
+                let accessed = self.access(value);
+                Some(quote!(#and #mut_ #accessed))
+            }
+            syn::Type::Path(_) => {
+                let (wrapper, elem) = generic_wrapper(ty)?;
+                let closure_arg = quote!(__portrait_newtype_value);
+                let unwrapped = self.unwrap(elem, closure_arg.clone())?;
+                Some(match wrapper {
+                    Wrapper::Option | Wrapper::Result => {
+                        quote!(#value.map(|#closure_arg| #unwrapped))
+                    }
+                    Wrapper::Vec => quote! {
+                        ::core::iter::Iterator::collect::<::std::vec::Vec<_>>(
+                            ::core::iter::Iterator::map(
+                                ::core::iter::IntoIterator::into_iter(#value),
+                                |#closure_arg| #unwrapped,
+                            ),
+                        )
+                    },
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// Converts a value of the inner type corresponding to `ty` to `ty`,
+    /// or returns `None` if the type is not supported.
+    fn wrap(&self, ty: &syn::Type, value: TokenStream) -> Option<TokenStream> {
+        if !portrait_framework::contains_self(ty) {
+            return Some(value);
+        }
+
+        match ty {
+            syn::Type::Paren(ty) => self.wrap(&ty.elem, value),
+            syn::Type::Group(ty) => self.wrap(&ty.elem, value),
+            _ if portrait_framework::is_self(ty) => Some(self.construct(value)),
+            syn::Type::Path(_) => {
+                let (wrapper, elem) = generic_wrapper(ty)?;
+                let closure_arg = quote!(__portrait_newtype_value);
+                let wrapped = self.wrap(elem, closure_arg.clone())?;
+                Some(match wrapper {
+                    Wrapper::Option | Wrapper::Result => {
+                        quote!(#value.map(|#closure_arg| #wrapped))
+                    }
+                    Wrapper::Vec => quote! {
+                        ::core::iter::Iterator::collect::<::std::vec::Vec<_>>(
+                            ::core::iter::Iterator::map(
+                                ::core::iter::IntoIterator::into_iter(#value),
+                                |#closure_arg| #wrapped,
+                            ),
+                        )
+                    },
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// Substitutes `self` in the accessor with `value`.
+    fn access(&self, value: TokenStream) -> TokenStream {
+        fn substitute(ts: TokenStream, value: &TokenStream) -> TokenStream {
+            ts.into_iter()
+                .flat_map(|tt| match tt {
+                    TokenTree::Ident(ident) if ident == "self" => {
+                        value.clone().into_iter().collect::<Vec<_>>()
+                    }
+                    TokenTree::Group(group) => {
+                        let mut new_group = proc_macro2::Group::new(
+                            group.delimiter(),
+                            substitute(group.stream(), value),
+                        );
+                        new_group.set_span(group.span());
+                        vec![TokenTree::Group(new_group)]
+                    }
+                    tt => vec![tt],
+                })
+                .collect()
+        }
+
+        let is_ident =
+            matches!(&value.clone().into_iter().collect::<Vec<_>>()[..], [TokenTree::Ident(_)]);
+        let value = if is_ident { value } else { quote!((#value)) };
+        substitute(self.accessor.to_token_stream(), &value)
+    }
+
+    /// Constructs `Self` from a value of the inner type.
+    fn construct(&self, value: TokenStream) -> TokenStream {
+        match &self.ctor {
+            None => quote!(Self(#value)),
+            Some(ctor @ syn::Expr::Path(_)) => quote!(#ctor(#value)),
+            Some(ctor) => quote!((#ctor)(#value)),
+        }
+    }
+}
+
+enum Wrapper {
+    Option,
+    Result,
+    Vec,
+}
+
+/// Matches `Option<T>`, `Result<T, E>` and `Vec<T>`, returning the wrapper and `T`.
+fn generic_wrapper(ty: &syn::Type) -> Option<(Wrapper, &syn::Type)> {
+    let syn::Type::Path(ty) = ty else { return None };
+    if ty.qself.is_some() {
+        return None;
+    }
+
+    let segment = ty.path.segments.last()?;
+    let wrapper = match segment.ident.to_string().as_str() {
+        "Option" => Wrapper::Option,
+        "Result" => Wrapper::Result,
+        "Vec" => Wrapper::Vec,
+        _ => return None,
+    };
+
+    let syn::PathArguments::AngleBracketed(args) = &segment.arguments else { return None };
+    let Some(syn::GenericArgument::Type(elem)) = args.args.first() else { return None };
+
+    // only the first type argument of `Result` is converted
+    let rest_contains_self = args.args.iter().skip(1).any(|arg| match arg {
+        syn::GenericArgument::Type(ty) => portrait_framework::contains_self(ty),
+        _ => false,
+    });
+    if rest_contains_self {
+        return None;
+    }
+
+    Some((wrapper, elem))
+}
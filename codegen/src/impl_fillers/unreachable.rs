@@ -0,0 +1,125 @@
+use portrait_framework::is_self;
+use quote::quote;
+use syn::spanned::Spanned;
+use syn::{Error, Result};
+
+use crate::util::{self, Args, ItemOverrides};
+
+pub(crate) struct Generator(pub(crate) Args<ItemOverrides>);
+impl portrait_framework::GenerateImpl for Generator {
+    fn generate_const(
+        &mut self,
+        ctx: portrait_framework::ImplContext,
+        item: &syn::TraitItemConst,
+    ) -> Result<syn::ImplItemConst> {
+        let Args(overrides) = &self.0;
+        overrides.check_names(ctx.all_trait_items)?;
+
+        let Some(expr) = overrides.value(&item.ident) else {
+            return Err(Error::new_spanned(
+                item,
+                format!(
+                    "portrait::unreachable cannot implement associated constants, specify a value \
+                     with `portrait::unreachable({} = ...)`",
+                    item.ident
+                ),
+            ));
+        };
+
+        Ok(syn::ImplItemConst {
+            attrs:       item
+                .attrs
+                .iter()
+                .filter(|attr| attr.path().is_ident("cfg"))
+                .cloned()
+                .collect(),
+            vis:         syn::Visibility::Inherited,
+            defaultness: None,
+            const_token: item.const_token,
+            ident:       item.ident.clone(),
+            generics:    item.generics.clone(),
+            colon_token: item.colon_token,
+            ty:          item.ty.clone(),
+            eq_token:    syn::Token![=](item.span()),
+            expr:        expr.clone(),
+            semi_token:  item.semi_token,
+        })
+    }
+
+    fn generate_fn(
+        &mut self,
+        ctx: portrait_framework::ImplContext,
+        item: &syn::TraitItemFn,
+    ) -> Result<syn::ImplItemFn> {
+        let Args(overrides) = &self.0;
+        overrides.check_names(ctx.all_trait_items)?;
+
+        let mut sig = item.sig.clone();
+
+        let block = if let Some(expr) = overrides.value(&sig.ident) {
+            sig = util::unuse_sig(sig);
+            syn::parse_quote!({ #expr })
+        } else {
+            let Some(syn::FnArg::Receiver(receiver)) = sig.inputs.first() else {
+                return Err(Error::new_spanned(
+                    &item.sig,
+                    format!(
+                        "portrait::unreachable cannot implement associated functions without a \
+                         receiver, specify a body with `portrait::unreachable({} = ...)`",
+                        sig.ident
+                    ),
+                ));
+            };
+
+            // a value of an uninhabited type can be matched without any arms
+            let self_token = receiver.self_token;
+            let scrutinee = if receiver.reference.is_none() && is_self(&receiver.ty) {
+                quote!(#self_token)
+            } else {
+                quote!(*#self_token)
+            };
+
+            if let Some(syn::FnArg::Receiver(receiver)) = sig.inputs.first_mut() {
+                if receiver.reference.is_none() {
+                    receiver.mutability = None;
+                }
+            }
+            sig = util::unuse_sig(sig);
+            syn::parse_quote!({ match #scrutinee {} })
+        };
+
+        Ok(syn::ImplItemFn {
+            attrs: item.attrs.iter().filter(|attr| attr.path().is_ident("cfg")).cloned().collect(),
+            vis: syn::Visibility::Inherited,
+            defaultness: None,
+            sig,
+            block,
+        })
+    }
+
+    fn generate_type(
+        &mut self,
+        ctx: portrait_framework::ImplContext,
+        item: &syn::TraitItemType,
+    ) -> Result<syn::ImplItemType> {
+        let Args(overrides) = &self.0;
+        overrides.check_names(ctx.all_trait_items)?;
+
+        let ty = match overrides.ty(&item.ident) {
+            Some(ty) => ty.clone(),
+            None => syn::parse_quote!(Self),
+        };
+
+        Ok(syn::ImplItemType {
+            attrs: item.attrs.iter().filter(|attr| attr.path().is_ident("cfg")).cloned().collect(),
+            vis: syn::Visibility::Inherited,
+            defaultness: None,
+            type_token: item.type_token,
+            ident: item.ident.clone(),
+            generics: item.generics.clone(),
+            eq_token: syn::Token![=](item.span()),
+            ty,
+            semi_token: item.semi_token,
+        })
+    }
+}
@@ -2,6 +2,19 @@ use proc_macro::TokenStream;
 
 mod util;
 
+#[cfg(all(
+    test,
+    feature = "closures-filler",
+    feature = "default-filler",
+    feature = "delegate-filler",
+    feature = "derive-delegate-filler",
+    feature = "log-filler",
+    feature = "newtype-filler",
+    feature = "rules-filler",
+    feature = "unreachable-filler",
+))]
+mod snapshot_tests;
+
 mod make;
 #[proc_macro_attribute]
 pub fn make(attr: TokenStream, item: TokenStream) -> TokenStream {
@@ -20,6 +33,43 @@ pub fn derive(attr: TokenStream, item: TokenStream) -> TokenStream {
     derive::run(attr.into(), item.into()).unwrap_or_else(|err| err.into_compile_error()).into()
 }
 
+mod extract;
+#[proc_macro_attribute]
+pub fn extract(attr: TokenStream, item: TokenStream) -> TokenStream {
+    extract::run(attr.into(), item.into()).unwrap_or_else(|err| err.into_compile_error()).into()
+}
+
+mod fill_inherent;
+#[proc_macro_attribute]
+pub fn fill_inherent(attr: TokenStream, item: TokenStream) -> TokenStream {
+    fill_inherent::run(attr.into(), item.into())
+        .unwrap_or_else(|err| err.into_compile_error())
+        .into()
+}
+
+#[doc(hidden)]
+#[proc_macro]
+pub fn fill_inherent_filler(input: TokenStream) -> TokenStream {
+    portrait_framework::impl_filler(input.into(), fill_inherent::Filler)
+        .unwrap_or_else(|err| err.into_compile_error())
+        .into()
+}
+
+#[cfg(feature = "closures-filler")]
+mod closures;
+#[cfg(feature = "closures-filler")]
+#[proc_macro_attribute]
+pub fn closures(attr: TokenStream, item: TokenStream) -> TokenStream {
+    closures::run(attr.into(), item.into()).unwrap_or_else(|err| err.into_compile_error()).into()
+}
+
+#[doc(hidden)]
+#[cfg(feature = "closures-filler")]
+#[proc_macro]
+pub fn closures_filler(input: TokenStream) -> TokenStream {
+    portrait_framework::completer_impl_filler(input, closures::Generator::new)
+}
+
 macro_rules! fillers {
     ($dir:ident $completer_filler:ident: $($names:ident = $feature:literal,)*) => {
         mod $dir {
@@ -47,4 +97,16 @@ fillers! { impl_fillers completer_impl_filler:
     default = "default-filler",
     delegate = "delegate-filler",
     log = "log-filler",
+    newtype = "newtype-filler",
+    unreachable = "unreachable-filler",
+}
+
+#[cfg(feature = "rules-filler")]
+mod rules;
+#[cfg(feature = "rules-filler")]
+#[proc_macro]
+pub fn rules(input: TokenStream) -> TokenStream {
+    portrait_framework::impl_filler(input.into(), rules::Filler)
+        .unwrap_or_else(|err| err.into_compile_error())
+        .into()
 }
@@ -1,11 +1,11 @@
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 
 use heck::ToSnakeCase;
-use proc_macro2::TokenStream;
+use proc_macro2::{Group, Punct, Spacing, TokenStream, TokenTree};
 use quote::{format_ident, quote, quote_spanned, ToTokens};
 use syn::parse::{Parse, ParseStream};
 use syn::spanned::Spanned;
-use syn::visit::{visit_path, Visit};
+use syn::visit_mut::{self, VisitMut};
 use syn::{parenthesized, Result};
 
 use crate::util;
@@ -14,25 +14,69 @@ use crate::util::{Once, ParseArgs};
 pub(crate) fn run(attr: TokenStream, item: TokenStream) -> Result<TokenStream> {
     let item = syn::parse2::<syn::ItemTrait>(item)?;
     let vis = &item.vis;
-    let unstripped_trait_items = item.items.clone();
+    let mut unstripped_trait_items = item.items.clone();
 
     let item_ident = &item.ident;
 
-    let util::Args(ItemArgs { debug_print, name: mod_name, imports, auto_imports }) =
+    let util::Args(ItemArgs { debug_print, name: mod_name, imports, auto_imports, export, module }) =
         syn::parse2::<util::Args<ItemArgs>>(attr)?;
     let mod_name =
         mod_name.get_or(|| format_ident!("{}_portrait", item.ident.to_string().to_snake_case()));
 
+    // absolute imports can be emitted by the macro directly,
+    // so that implementors do not need to import the portrait module
+    let absolute_imports: Option<Vec<_>> = imports.iter().map(absolute_use_tree).collect();
+
     let mut imports: Vec<_> = imports.into_iter().map(ToTokens::into_token_stream).collect();
     if auto_imports.get_or(|| false) {
         let mut import_collector = ImportCollector::default();
-        for trait_item in &item.items {
-            import_collector.visit_trait_item(trait_item)
+        import_collector.push_generics(&item.generics);
+        for trait_item in &mut unstripped_trait_items {
+            import_collector.visit_trait_item_mut(trait_item);
         }
 
-        imports.extend(import_collector.idents.iter().map(|ident| quote!(super::super::#ident)));
+        let mut imported_names = HashSet::new();
+        for (supers, ident) in import_collector.imports.into_values() {
+            if !imported_names.insert(ident.clone()) {
+                return Err(syn::Error::new_spanned(
+                    &ident,
+                    format_args!(
+                        "auto_imports cannot import `{ident}` from multiple modules, use \
+                         `import(...)` instead"
+                    ),
+                ));
+            }
+
+            let supers = (0..supers).map(|_| quote!(super::));
+            imports.push(quote!(super::super::#(#supers)* #ident));
+        }
     }
 
+    let module = module.try_get();
+    let macro_imports = match (absolute_imports, imports.len(), &module) {
+        (Some(absolute_imports), len, _) if len == absolute_imports.len() => {
+            quote!(#(#[allow(unused_imports)] use #absolute_imports;)*)
+        }
+        // the derived module can be located from any crate through `$crate`
+        (_, _, Some(module)) => {
+            let imports_path = dollar_crate(quote!(#module::#mod_name::imports::*));
+            quote!(#[allow(unused_imports)] use #imports_path;)
+        }
+        _ => quote!($($imports)*),
+    };
+    // fails to compile if `module` does not contain the derived module
+    let module_check = module.map(|module| {
+        quote! {
+            #[allow(unused_imports)]
+            use #module::#mod_name as _;
+        }
+    });
+
+    // unique name required because macro may be exported despite unused
+    let macro_name = macro_name(&item, &mod_name);
+    let facade_export = export.get_or(|| Export::MacroExport) == Export::Facade;
+    // macros without `#[macro_export]` cannot be re-exported beyond the crate,
+    // so the facade export still exports the macro under its hidden name
     let pub_export = match vis {
         syn::Visibility::Public(_) => quote! {
             #[doc(hidden)]
@@ -40,8 +84,22 @@ pub(crate) fn run(attr: TokenStream, item: TokenStream) -> Result<TokenStream> {
         },
         _ => quote!(),
     };
-    // random name required because macro may be exported despite unused
-    let macro_random_name = format_ident!("portrait_items_{:x}", rand::random::<u128>());
+    let macro_alias = match vis {
+        syn::Visibility::Public(_) if facade_export => quote! {
+            #[doc(hidden)]
+            pub use #mod_name::__portraits::#item_ident;
+        },
+        _ => quote!(#vis use #macro_name as #item_ident;),
+    };
+    let portraits_mod = match vis {
+        syn::Visibility::Public(_) if facade_export => quote! {
+            #[doc(hidden)]
+            pub mod __portraits {
+                pub use #macro_name as #item_ident;
+            }
+        },
+        _ => quote!(),
+    };
 
     let import_vis = match vis {
         syn::Visibility::Inherited => quote_spanned!(vis.span() => pub(in super::super)),
@@ -62,36 +120,80 @@ pub(crate) fn run(attr: TokenStream, item: TokenStream) -> Result<TokenStream> {
     };
 
     let item_stripped = util::strip_attr("portrait", &item, syn::visit_mut::visit_item_trait_mut);
+    let protocol_version = util::protocol_version();
+    // `crate::` paths in the portrait refer to the crate declaring the trait,
+    // even when the macro is invoked from another crate
+    let rules_items: Vec<_> = unstripped_trait_items
+        .iter()
+        .filter_map(portrait_framework::rules::encode_trait_item)
+        .map(dollar_crate)
+        .collect();
+    let portrait_items: Vec<_> =
+        unstripped_trait_items.iter().map(|item| dollar_crate(item.to_token_stream())).collect();
 
     let output = quote! {
         #item_stripped
 
         #pub_export
-        macro_rules! #macro_random_name {
+        macro_rules! #macro_name {
+            (
+                @RULES {$target_macro:path}
+                $(@ARGS { $($args:tt)* })?
+            ) => {
+                #(
+                    $target_macro! {
+                        @ARGS { $($($args)*)? }
+                        #rules_items
+                    }
+                )*
+            };
             (
                 @TARGET {$target_macro:path}
+                @IMPORTS { $($imports:tt)* }
                 $(
                     @$arg_key:ident { $($arg_value:tt)* }
                 )*
             ) => {
+                #macro_imports
+
                 $target_macro! {
-                    TRAIT_PORTRAIT { #({#unstripped_trait_items})* }
+                    PORTRAIT_PROTOCOL { #protocol_version }
+                    TRAIT_PORTRAIT { #({#portrait_items})* }
                     $(
                         $arg_key { $($arg_value)* }
                     )*
                 }
-            }
+            };
+            (
+                @TARGET {$target_macro:path}
+                $(
+                    @$arg_key:ident { $($arg_value:tt)* }
+                )*
+            ) => {
+                $target_macro! {
+                    PORTRAIT_PROTOCOL { #protocol_version }
+                    TRAIT_PORTRAIT { #({#portrait_items})* }
+                    $(
+                        $arg_key { $($arg_value)* }
+                    )*
+                }
+            };
         }
 
         #[allow(non_snake_case)]
-        #vis use #macro_random_name as #item_ident;
+        #macro_alias
 
         #[allow(non_snake_case)]
         #vis mod #mod_name {
+            #[allow(unused_imports)]
             pub mod imports {
                 #(#import_vis use #imports;)*
             }
+
+            #portraits_mod
         }
+
+        #module_check
     };
     if debug_print.get_or(|| false) {
         println!("{output}");
@@ -99,12 +201,44 @@ pub(crate) fn run(attr: TokenStream, item: TokenStream) -> Result<TokenStream> {
     Ok(output)
 }
 
+/// Derives a deterministic name for the portrait macro.
+///
+/// Exported macros share the namespace of the crate root,
+/// so the name includes the trait name
+/// and a hash of the crate name, the derived module name and the trait tokens.
+/// The name does not depend on where the trait is declared,
+/// so identical traits with the same derived module name in one crate get the same macro name
+/// and are rejected by the compiler as duplicate macro definitions;
+/// they have to be distinguished with an explicit `name`.
+fn macro_name(item: &syn::ItemTrait, mod_name: &syn::Ident) -> syn::Ident {
+    let crate_name = std::env::var("CARGO_CRATE_NAME").unwrap_or_default();
+    let hash = util::stable_hash(&[
+        &crate_name,
+        &mod_name.to_string(),
+        &item.to_token_stream().to_string(),
+    ]);
+    let trait_name = item.ident.to_string().to_snake_case();
+    format_ident!("portrait_items_{trait_name}_{hash:016x}")
+}
+
 #[derive(Default)]
 struct ItemArgs {
     debug_print:  Once<bool>,
     name:         Once<syn::Ident>,
     imports:      Vec<syn::UseTree>,
     auto_imports: Once<bool>,
+    export:       Once<Export>,
+    module:       Once<syn::Path>,
+}
+
+/// Determines how the portrait macro of a `pub` trait is exported.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Export {
+    /// Export the macro at the crate root with `#[macro_export]`.
+    MacroExport,
+    /// Also export the macro at the crate root,
+    /// but re-export it through the `__portraits` module of the derived module.
+    Facade,
 }
 
 mod kw {
@@ -112,6 +246,10 @@ mod kw {
     syn::custom_keyword!(name);
     syn::custom_keyword!(import);
     syn::custom_keyword!(auto_imports);
+    syn::custom_keyword!(export);
+    syn::custom_keyword!(macro_export);
+    syn::custom_keyword!(facade);
+    syn::custom_keyword!(module);
 }
 
 impl ParseArgs for ItemArgs {
@@ -132,7 +270,33 @@ impl ParseArgs for ItemArgs {
             self.imports.extend(imports);
         } else if lh.peek(kw::auto_imports) {
             let key = input.parse::<kw::auto_imports>()?;
-            self.debug_print.set(true, key.span())?;
+            self.auto_imports.set(true, key.span())?;
+        } else if lh.peek(kw::export) {
+            let key = input.parse::<kw::export>()?;
+            _ = input.parse::<syn::Token![=]>()?;
+
+            let lh = input.lookahead1();
+            let export = if lh.peek(kw::macro_export) {
+                input.parse::<kw::macro_export>()?;
+                Export::MacroExport
+            } else if lh.peek(kw::facade) {
+                input.parse::<kw::facade>()?;
+                Export::Facade
+            } else {
+                return Err(lh.error());
+            };
+            self.export.set(export, key.span())?;
+        } else if lh.peek(kw::module) {
+            let key = input.parse::<kw::module>()?;
+            _ = input.parse::<syn::Token![=]>()?;
+            let path: syn::Path = input.parse()?;
+            if path.leading_colon.is_some() || path.segments[0].ident != "crate" {
+                return Err(syn::Error::new_spanned(
+                    path,
+                    "`module` must be the absolute path of the trait module starting with `crate`",
+                ));
+            }
+            self.module.set(path, key.span())?;
         } else {
             return Err(lh.error());
         }
@@ -140,18 +304,217 @@ impl ParseArgs for ItemArgs {
     }
 }
 
+/// Converts a use tree that resolves to the same item from any module of the crate
+/// to a form usable in the macro output, or returns `None` for relative use trees.
+fn absolute_use_tree(tree: &syn::UseTree) -> Option<TokenStream> {
+    let syn::UseTree::Path(path) = tree else { return None };
+    if path.ident == "crate" {
+        let rest = &path.tree;
+        Some(quote!($crate::#rest))
+    } else if ["std", "core", "alloc"].iter().any(|name| path.ident == name) {
+        Some(quote!(#tree))
+    } else {
+        None
+    }
+}
+
+/// Replaces `crate::` in a token stream with `$crate::`.
+fn dollar_crate(ts: TokenStream) -> TokenStream {
+    let mut output = Vec::new();
+    let mut tokens = ts.into_iter().peekable();
+    while let Some(token) = tokens.next() {
+        match token {
+            TokenTree::Ident(ident) if ident == "crate" => {
+                let is_path = matches!(
+                    tokens.peek(),
+                    Some(TokenTree::Punct(punct)) if punct.as_char() == ':'
+                        && punct.spacing() == Spacing::Joint
+                );
+                let is_dollar_crate = matches!(
+                    output.last(),
+                    Some(TokenTree::Punct(punct)) if punct.as_char() == '$'
+                );
+                if is_path && !is_dollar_crate {
+                    let mut dollar = Punct::new('$', Spacing::Alone);
+                    dollar.set_span(ident.span());
+                    output.push(TokenTree::Punct(dollar));
+                }
+                output.push(TokenTree::Ident(ident));
+            }
+            TokenTree::Group(group) => {
+                let mut new_group = Group::new(group.delimiter(), dollar_crate(group.stream()));
+                new_group.set_span(group.span());
+                output.push(TokenTree::Group(new_group));
+            }
+            token => output.push(token),
+        }
+    }
+    output.into_iter().collect()
+}
+
+/// Names that resolve without imports in any module.
+const PRELUDE_NAMES: &[&str] = &[
+    // primitive types
+    "bool",
+    "char",
+    "str",
+    "i8",
+    "i16",
+    "i32",
+    "i64",
+    "i128",
+    "isize",
+    "u8",
+    "u16",
+    "u32",
+    "u64",
+    "u128",
+    "usize",
+    "f32",
+    "f64",
+    // std prelude
+    "Copy",
+    "Send",
+    "Sized",
+    "Sync",
+    "Unpin",
+    "Drop",
+    "Fn",
+    "FnMut",
+    "FnOnce",
+    "drop",
+    "Box",
+    "ToOwned",
+    "Clone",
+    "PartialEq",
+    "PartialOrd",
+    "Eq",
+    "Ord",
+    "AsRef",
+    "AsMut",
+    "Into",
+    "From",
+    "Default",
+    "Iterator",
+    "Extend",
+    "IntoIterator",
+    "DoubleEndedIterator",
+    "ExactSizeIterator",
+    "Option",
+    "Some",
+    "None",
+    "Result",
+    "Ok",
+    "Err",
+    "String",
+    "ToString",
+    "Vec",
+    "TryFrom",
+    "TryInto",
+    "FromIterator",
+    // extern crates in the extern prelude
+    "std",
+    "core",
+    "alloc",
+];
+
+/// Collects the names used in the trait items that need to be imported in the impl scope,
+/// and rewrites `self::`/`super::` paths to the imported names.
 #[derive(Default)]
 struct ImportCollector {
-    idents: HashSet<syn::Ident>,
+    /// Maps the imported path to the number of `super::` relative to the trait module
+    /// and the imported name.
+    imports:        BTreeMap<String, (usize, syn::Ident)>,
+    /// Generic parameters in scope.
+    generic_scopes: Vec<HashSet<syn::Ident>>,
 }
 
-impl<'ast> Visit<'ast> for ImportCollector {
-    fn visit_path(&mut self, path: &'ast syn::Path) {
+impl ImportCollector {
+    fn push_generics(&mut self, generics: &syn::Generics) {
+        self.generic_scopes.push(
+            generics
+                .type_params()
+                .map(|param| param.ident.clone())
+                .chain(generics.const_params().map(|param| param.ident.clone()))
+                .collect(),
+        );
+    }
+
+    fn is_generic(&self, ident: &syn::Ident) -> bool {
+        self.generic_scopes.iter().any(|scope| scope.contains(ident))
+    }
+
+    fn with_generics(&mut self, generics: &syn::Generics, f: impl FnOnce(&mut Self)) {
+        self.push_generics(generics);
+        f(self);
+        self.generic_scopes.pop();
+    }
+}
+
+impl VisitMut for ImportCollector {
+    fn visit_path_mut(&mut self, path: &mut syn::Path) {
         if path.leading_colon.is_none() {
-            let segment = path.segments.first().expect("path segments should be nonempty");
-            self.idents.insert(segment.ident.clone());
+            // `self::a::b` and `super::a::b` are relative to the trait module,
+            // so they are rewritten to `a::b` with `a` imported relative to the trait module.
+            let mut supers = 0;
+            let mut relative = false;
+            for segment in &path.segments {
+                if segment.ident == "self" && !relative {
+                    relative = true;
+                } else if segment.ident == "super" {
+                    relative = true;
+                    supers += 1;
+                } else {
+                    break;
+                }
+            }
+            let skipped = path.segments.len().min(supers + usize::from(relative && supers == 0));
+
+            if skipped < path.segments.len() {
+                if relative {
+                    path.segments = path.segments.iter().skip(skipped).cloned().collect();
+                }
+
+                let ident = &path.segments[0].ident;
+                let skip = !relative
+                    && (ident == "Self"
+                        || ident == "crate"
+                        || self.is_generic(ident)
+                        || PRELUDE_NAMES.iter().any(|name| ident == name));
+                if !skip {
+                    let key = format!("{}{ident}", "super::".repeat(supers));
+                    self.imports.insert(key, (supers, ident.clone()));
+                }
+            }
         }
 
-        visit_path(self, path)
+        visit_mut::visit_path_mut(self, path)
     }
+
+    fn visit_trait_item_fn_mut(&mut self, item: &mut syn::TraitItemFn) {
+        // only the signature is relevant to implementors
+        self.with_generics(&item.sig.generics.clone(), |this| {
+            this.visit_signature_mut(&mut item.sig)
+        });
+    }
+
+    fn visit_trait_item_const_mut(&mut self, item: &mut syn::TraitItemConst) {
+        self.with_generics(&item.generics.clone(), |this| {
+            this.visit_generics_mut(&mut item.generics);
+            this.visit_type_mut(&mut item.ty);
+        });
+    }
+
+    fn visit_trait_item_type_mut(&mut self, item: &mut syn::TraitItemType) {
+        self.with_generics(&item.generics.clone(), |this| {
+            this.visit_generics_mut(&mut item.generics);
+            for bound in &mut item.bounds {
+                this.visit_type_param_bound_mut(bound);
+            }
+        });
+    }
+
+    fn visit_attribute_mut(&mut self, _: &mut syn::Attribute) {}
+
+    fn visit_block_mut(&mut self, _: &mut syn::Block) {}
 }
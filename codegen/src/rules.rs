@@ -0,0 +1,62 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::Result;
+
+pub(crate) struct Filler;
+
+pub(crate) struct Args {
+    target: syn::Path,
+    args:   TokenStream,
+}
+
+impl Parse for Args {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let target = input.parse()?;
+
+        let mut args = TokenStream::new();
+        if !input.is_empty() {
+            let _: syn::Token![,] = input.parse()?;
+            args = input.parse()?;
+        }
+
+        Ok(Self { target, args })
+    }
+}
+
+impl portrait_framework::FillImpl for Filler {
+    type Args = Args;
+
+    fn fill(
+        self,
+        portrait: &[syn::TraitItem],
+        Args { target, args }: Args,
+        item_impl: &syn::ItemImpl,
+    ) -> Result<TokenStream> {
+        let missing = portrait_framework::subtract_items(portrait, item_impl)?;
+
+        let mut output = item_impl.clone();
+        for trait_item in portrait {
+            let is_missing = match trait_item {
+                syn::TraitItem::Const(item) => missing.consts.contains_key(&item.ident),
+                syn::TraitItem::Fn(item) => missing.fns.contains_key(&item.sig.ident),
+                syn::TraitItem::Type(item) => missing.types.contains_key(&item.ident),
+                _ => false,
+            };
+            if !is_missing {
+                continue;
+            }
+
+            if let Some(encoded) = portrait_framework::rules::encode_trait_item(trait_item) {
+                output.items.push(syn::parse_quote! {
+                    #target! {
+                        @ARGS { #args }
+                        #encoded
+                    }
+                });
+            }
+        }
+
+        Ok(quote!(#output))
+    }
+}
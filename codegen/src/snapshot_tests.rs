@@ -0,0 +1,441 @@
+//! Snapshots of the built-in filler output.
+//!
+//! Run `PORTRAIT_UPDATE_SNAPSHOTS=1 cargo test --workspace` to update the snapshots
+//! after changing a filler.
+//! The module is only compiled with all filler features enabled,
+//! which the workspace does through the `portrait` crate;
+//! when testing this crate alone, run `cargo test -p portrait-codegen --all-features`.
+
+use portrait_framework::testing::{
+    assert_snapshot, expand_derive_completer, expand_impl_completer, expand_impl_filler,
+    pretty_print,
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+fn snapshot(name: &str, output: syn::Result<TokenStream>) {
+    let output = output.unwrap_or_else(|err| panic!("filler failed: {err}"));
+    assert_snapshot(format!("tests/snapshots/{name}.rs"), &pretty_print(&output));
+}
+
+fn sample_trait() -> TokenStream {
+    quote! {
+        trait Sample {
+            const SIZE: usize;
+            type Output;
+            fn new(value: u32) -> Self;
+            fn get(&self, index: usize) -> Option<u32>;
+            fn set(&mut self, index: usize, value: u32) -> bool;
+            async fn flush(&mut self);
+        }
+    }
+}
+
+#[test]
+fn closures() {
+    snapshot(
+        "closures",
+        expand_impl_completer(
+            crate::closures::Generator::new,
+            quote! {
+                trait Store {
+                    type Key;
+                    fn get(&self, key: &Self::Key) -> Option<u32>;
+                    fn set(&mut self, key: Self::Key, value: u32);
+                    fn name<'a>(&self, prefix: &'a str) -> &'a str;
+                    fn label(&self) -> &str;
+                    fn first(self, items: &[u32]) -> &u32;
+                    fn merge(self, other: Self) -> Self;
+                }
+            },
+            quote!(pub),
+            quote! {
+                impl Store for StoreFns {
+                    type Key = String;
+                }
+            },
+        ),
+    );
+}
+
+#[test]
+fn default() {
+    snapshot(
+        "default",
+        expand_impl_completer(
+            crate::impl_fillers::default::Generator,
+            sample_trait(),
+            quote!(),
+            quote! {
+                impl Sample for Foo {
+                    type Output = ();
+                }
+            },
+        ),
+    );
+}
+
+#[test]
+fn default_values() {
+    snapshot(
+        "default_values",
+        expand_impl_completer(
+            crate::impl_fillers::default::Generator,
+            sample_trait(),
+            quote!(SIZE = 8, for Option<u32> = Some(1), get = None, types(Output = u8)),
+            quote!(impl Sample for Foo {}),
+        ),
+    );
+}
+
+#[test]
+fn default_unknown_name() {
+    let err = expand_impl_completer(
+        crate::impl_fillers::default::Generator,
+        sample_trait(),
+        quote!(SIZ = 8, types(Output = u8)),
+        quote!(impl Sample for Foo {}),
+    )
+    .unwrap_err();
+    assert_eq!(err.to_string(), "no associated constant or function called `SIZ` in trait");
+}
+
+#[test]
+fn delegate() {
+    snapshot(
+        "delegate",
+        expand_impl_completer(
+            crate::impl_fillers::delegate::Generator,
+            sample_trait(),
+            quote!(Inner; self.inner),
+            quote! {
+                impl Sample for Foo {
+                    fn new(value: u32) -> Self { Self { inner: Inner::new(value) } }
+                }
+            },
+        ),
+    );
+}
+
+#[test]
+fn delegate_map_err() {
+    snapshot(
+        "delegate_map_err",
+        expand_impl_completer(
+            crate::impl_fillers::delegate::Generator,
+            quote! {
+                trait Store {
+                    type Error;
+                    type Key;
+                    fn get(&self, key: Self::Key) -> Result<u32, Self::Error>;
+                    fn next(&mut self) -> Option<Result<u32, Self::Error>>;
+                    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>>;
+                }
+            },
+            quote!(Inner; self.inner; map_err = Into::into),
+            quote! {
+                impl Store for Foo {
+                    type Error = FooError;
+                }
+            },
+        ),
+    );
+}
+
+#[test]
+fn delegate_map_err_unused() {
+    let err = expand_impl_completer(
+        crate::impl_fillers::delegate::Generator,
+        quote! {
+            trait Store {
+                type Error;
+                type Key;
+                fn get(&self, key: Self::Key) -> Result<u32, Self::Error>;
+                fn clear(&mut self) -> Result<(), Self::Key>;
+            }
+        },
+        quote!(Inner; self.inner; map_err = Into::into),
+        quote! {
+            impl Store for Foo {
+                type Error = FooError;
+                fn get(&self, key: Self::Key) -> Result<u32, Self::Error> { todo!() }
+            }
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "`map_err` does not apply to any generated function; it only converts `Result<T, \
+         Self::X>`, `Poll<Result<T, Self::X>>` and `Option<Result<T, Self::X>>` where `X` is an \
+         associated type specified in the impl block"
+    );
+}
+
+#[test]
+fn log() {
+    snapshot(
+        "log",
+        expand_impl_completer(
+            crate::impl_fillers::log::Generator,
+            quote! {
+                trait Logged {
+                    fn get(&self, index: usize) -> String;
+                    fn set(&mut self, index: usize, value: u32) -> String;
+                }
+            },
+            quote!(format),
+            quote!(impl Logged for Foo {}),
+        ),
+    );
+}
+
+#[test]
+fn newtype() {
+    snapshot(
+        "newtype",
+        expand_impl_completer(
+            crate::impl_fillers::newtype::Generator,
+            quote! {
+                trait Merge {
+                    const EMPTY: Self;
+                    fn merge(&self, other: &Self) -> Self;
+                    fn merge_all(items: Vec<Self>) -> Option<Self>;
+                    async fn load() -> Result<Self, String>;
+                }
+            },
+            quote!(Inner; self.0),
+            quote!(impl Merge for Foo {}),
+        ),
+    );
+}
+
+#[test]
+fn derive_delegate_struct() {
+    snapshot(
+        "derive_delegate_struct",
+        expand_derive_completer(
+            crate::derive_fillers::derive_delegate::Generator,
+            quote! {
+                trait Shape {
+                    #[portrait(derive_delegate(reduce = |a, b| a + b))]
+                    fn area(&self) -> f64;
+                    fn scale(&mut self, factor: f64);
+                    fn unit() -> Self;
+                }
+            },
+            quote!(),
+            quote! {
+                struct Pair {
+                    a: Circle,
+                    b: Square,
+                }
+            },
+        ),
+    );
+}
+
+#[test]
+fn derive_delegate_enum() {
+    snapshot(
+        "derive_delegate_enum",
+        expand_derive_completer(
+            crate::derive_fillers::derive_delegate::Generator,
+            quote! {
+                trait Shape {
+                    fn area(&self) -> f64;
+                    fn scale(&mut self, factor: f64);
+                }
+            },
+            quote!(),
+            quote! {
+                enum AnyShape {
+                    Circle(Circle),
+                    Square { square: Square },
+                }
+            },
+        ),
+    );
+}
+
+#[test]
+fn derive_delegate_field_args_mismatch() {
+    let shape = quote! {
+        trait Shape {
+            fn area(&self) -> f64;
+        }
+    };
+
+    let err = expand_derive_completer(
+        crate::derive_fillers::derive_delegate::Generator,
+        shape.clone(),
+        quote!(),
+        quote! {
+            struct Pair {
+                #[portrait(derive_delegate(tag = 0))]
+                a: Circle,
+            }
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err.to_string(), "`tag` is only supported on union fields, not on struct fields");
+
+    let err = expand_derive_completer(
+        crate::derive_fillers::derive_delegate::Generator,
+        shape,
+        quote!(),
+        quote! {
+            #[portrait(derive_delegate(union_tag = self.tag(), unsafe_union))]
+            union RawShape {
+                #[portrait(derive_delegate(tag = 0, priority = 1))]
+                circle: ManuallyDrop<Circle>,
+            }
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "`priority` is not supported on union fields because a union delegates to a single field"
+    );
+}
+
+#[test]
+fn derive_delegate_unused_priority() {
+    let err = expand_derive_completer(
+        crate::derive_fillers::derive_delegate::Generator,
+        quote! {
+            trait Hooks {
+                fn start(&mut self);
+                #[portrait(derive_delegate(order = reverse))]
+                fn shutdown(&mut self);
+            }
+        },
+        quote!(),
+        quote! {
+            struct Service {
+                database: Hook,
+                #[portrait(derive_delegate(priority = 10))]
+                server: Hook,
+            }
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "`priority` has no effect because no function of the trait uses \
+         `#[portrait(derive_delegate(order = priority))]`"
+    );
+}
+
+#[test]
+fn derive_delegate_conflicting_strategies_single_field() {
+    let err = expand_derive_completer(
+        crate::derive_fillers::derive_delegate::Generator,
+        quote! {
+            trait Lookup {
+                #[portrait(derive_delegate(first_some, collect))]
+                fn find(&self, key: u32) -> Option<u32>;
+            }
+        },
+        quote!(),
+        quote!(
+            struct Single(Inner);
+        ),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Only one of `reduce`, short-circuiting strategies, `collect` or `chain` can be used"
+    );
+}
+
+#[test]
+fn rules() {
+    snapshot(
+        "rules",
+        expand_impl_filler(
+            crate::rules::Filler,
+            sample_trait(),
+            quote!(my_filler, extra),
+            quote! {
+                impl Sample for Foo {
+                    type Output = ();
+                }
+            },
+        ),
+    );
+}
+
+#[test]
+fn fill_inherent() {
+    snapshot(
+        "fill_inherent",
+        expand_impl_filler(
+            crate::fill_inherent::Filler,
+            sample_trait(),
+            quote!(pub Sample; SIZE),
+            quote! {
+                impl Foo {
+                    fn new(value: u32) -> Self { Self(value) }
+                }
+            },
+        ),
+    );
+}
+
+#[test]
+fn unreachable() {
+    snapshot(
+        "unreachable",
+        expand_impl_completer(
+            crate::impl_fillers::unreachable::Generator,
+            sample_trait(),
+            quote!(SIZE = 0, new = panic!("uninhabited")),
+            quote!(impl Sample for Never {}),
+        ),
+    );
+}
+
+#[test]
+fn extract_impl_generics() {
+    let err = crate::extract::run(
+        quote!(pub trait Buffer),
+        quote! {
+            impl<T> Ring<T> {
+                pub fn push(&mut self, value: T) {}
+            }
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Cannot extract a signature that uses the generic parameter `T` of the impl block"
+    );
+
+    let err = crate::extract::run(
+        quote!(pub trait Buffer),
+        quote! {
+            impl<const N: usize> Ring<N> {
+                pub fn drain(&mut self) -> [u8; N] {}
+            }
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Cannot extract a signature that uses the generic parameter `N` of the impl block"
+    );
+
+    let err = crate::extract::run(
+        quote!(pub trait Buffer),
+        quote! {
+            impl<const N: usize> Ring<N> {
+                pub fn half(self) -> Ring<{ N / 2 }> {}
+            }
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Cannot extract a signature that uses the generic parameter `N` of the impl block"
+    );
+}
@@ -3,6 +3,8 @@
 use proc_macro2::{Span, TokenStream, TokenTree};
 use quote::ToTokens;
 use syn::parse::{Parse, ParseStream, Parser};
+use syn::spanned::Spanned;
+use syn::visit::Visit;
 use syn::Result;
 
 pub(crate) struct Once<T>(pub(crate) Option<(Span, T)>);
@@ -56,6 +58,101 @@ fn parse_args(input: ParseStream, args: &mut impl ParseArgs) -> Result<()> {
     Ok(())
 }
 
+/// Named values shared by fillers that cannot derive some items from the trait,
+/// parsed from `NAME = expr` for constants and functions
+/// and `types(Name = Ty)` for associated types.
+#[derive(Default)]
+pub(crate) struct ItemOverrides {
+    values: Vec<(syn::Ident, syn::Expr)>,
+    types:  Vec<(syn::Ident, syn::Type)>,
+}
+
+mod kw {
+    syn::custom_keyword!(types);
+}
+
+impl ParseArgs for ItemOverrides {
+    fn parse_once(&mut self, input: ParseStream) -> Result<()> {
+        if input.peek(kw::types) && input.peek2(syn::token::Paren) {
+            _ = input.parse::<kw::types>()?;
+            let inner;
+            _ = syn::parenthesized!(inner in input);
+            for pair in inner.parse_terminated(
+                |input| {
+                    let ident: syn::Ident = input.parse()?;
+                    _ = input.parse::<syn::Token![=]>()?;
+                    let ty: syn::Type = input.parse()?;
+                    Ok((ident, ty))
+                },
+                syn::Token![,],
+            )? {
+                if self.types.iter().any(|(other, _)| *other == pair.0) {
+                    return Err(syn::Error::new_spanned(&pair.0, "duplicate value for this type"));
+                }
+                self.types.push(pair);
+            }
+        } else {
+            let ident: syn::Ident = input.parse()?;
+            _ = input.parse::<syn::Token![=]>()?;
+            let expr = input.parse()?;
+
+            if self.values.iter().any(|(other, _)| *other == ident) {
+                return Err(syn::Error::new_spanned(ident, "duplicate value for this item"));
+            }
+            self.values.push((ident, expr));
+        }
+
+        Ok(())
+    }
+}
+
+impl ItemOverrides {
+    /// Ensures that the overrides refer to items of the trait.
+    pub(crate) fn check_names(&self, trait_items: &[syn::TraitItem]) -> Result<()> {
+        let has_value = |ident: &syn::Ident| {
+            trait_items.iter().any(|item| match item {
+                syn::TraitItem::Const(item) => item.ident == *ident,
+                syn::TraitItem::Fn(item) => item.sig.ident == *ident,
+                _ => false,
+            })
+        };
+        let has_type = |ident: &syn::Ident| {
+            trait_items
+                .iter()
+                .any(|item| matches!(item, syn::TraitItem::Type(item) if item.ident == *ident))
+        };
+
+        for (ident, _) in &self.values {
+            if !has_value(ident) {
+                return Err(syn::Error::new_spanned(
+                    ident,
+                    format!("no associated constant or function called `{ident}` in trait"),
+                ));
+            }
+        }
+        for (ident, _) in &self.types {
+            if !has_type(ident) {
+                return Err(syn::Error::new_spanned(
+                    ident,
+                    format!("no associated type called `{ident}` in trait"),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The value specified for the constant or function `ident`.
+    pub(crate) fn value(&self, ident: &syn::Ident) -> Option<&syn::Expr> {
+        self.values.iter().find(|(other, _)| other == ident).map(|(_, expr)| expr)
+    }
+
+    /// The type specified for the associated type `ident`.
+    pub(crate) fn ty(&self, ident: &syn::Ident) -> Option<&syn::Type> {
+        self.types.iter().find(|(other, _)| other == ident).map(|(_, ty)| ty)
+    }
+}
+
 pub(crate) fn set_sig_arg_span(sig: &mut syn::Signature, span: Span) -> Result<()> {
     for input in &mut sig.inputs {
         match input {
@@ -164,3 +261,114 @@ pub(crate) fn strip_attr<T: Clone>(
     visit_mut(&mut visitor, &mut item_stripped);
     item_stripped
 }
+
+/// Replaces the argument patterns of a signature with plain identifiers
+/// and returns the expressions to forward the arguments to another function with the same signature.
+///
+/// Non-identifier patterns are renamed to `__portrait_arg_{n}`.
+pub(crate) fn normalize_arg_pats(sig: &mut syn::Signature) -> Vec<syn::Expr> {
+    sig.inputs
+        .iter_mut()
+        .enumerate()
+        .map(|(ord, input)| match input {
+            syn::FnArg::Receiver(receiver) => {
+                if receiver.reference.is_none() {
+                    receiver.mutability = None;
+                }
+                let self_token = receiver.self_token;
+                syn::parse_quote!(#self_token)
+            }
+            syn::FnArg::Typed(pat_ty) => {
+                let ident = match &*pat_ty.pat {
+                    syn::Pat::Ident(pat_ident) if pat_ident.subpat.is_none() => {
+                        pat_ident.ident.clone()
+                    }
+                    pat => quote::format_ident!("__portrait_arg_{ord}", span = pat.span()),
+                };
+                *pat_ty.pat = syn::Pat::Ident(syn::PatIdent {
+                    attrs:      Vec::new(),
+                    by_ref:     None,
+                    mutability: None,
+                    ident:      ident.clone(),
+                    subpat:     None,
+                });
+                syn::parse_quote!(#ident)
+            }
+        })
+        .collect()
+}
+
+/// Returns the turbofish that passes the type and const generics of a signature explicitly,
+/// or `None` if there are no such generics
+/// or they cannot be specified due to argument-position `impl Trait`.
+pub(crate) fn forward_turbofish(sig: &syn::Signature) -> Option<TokenStream> {
+    struct Visitor(bool);
+
+    impl<'ast> Visit<'ast> for Visitor {
+        fn visit_type_impl_trait(&mut self, _: &'ast syn::TypeImplTrait) { self.0 = true; }
+    }
+
+    let mut visitor = Visitor(false);
+    for input in &sig.inputs {
+        if let syn::FnArg::Typed(pat_ty) = input {
+            visitor.visit_type(&pat_ty.ty);
+        }
+    }
+    if visitor.0 {
+        return None;
+    }
+
+    let generic_args: Vec<&syn::Ident> = sig
+        .generics
+        .params
+        .iter()
+        .filter_map(|param| match param {
+            syn::GenericParam::Type(param) => Some(&param.ident),
+            syn::GenericParam::Const(param) => Some(&param.ident),
+            syn::GenericParam::Lifetime(_) => None,
+        })
+        .collect();
+    (!generic_args.is_empty()).then(|| quote::quote!(::<#(#generic_args),*>))
+}
+
+/// The protocol version literal to tag generated portrait tokens with.
+pub(crate) fn protocol_version() -> syn::LitInt {
+    syn::LitInt::new(&portrait_framework::PROTOCOL_VERSION.to_string(), Span::call_site())
+}
+
+/// Hashes strings with 64-bit FNV-1a,
+/// which is stable across compiler versions and builds unlike `std::hash`.
+pub(crate) fn stable_hash(parts: &[&str]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    let mut hash = OFFSET_BASIS;
+    for part in parts {
+        // separate the parts so that moving bytes across parts changes the hash
+        for &byte in part.as_bytes().iter().chain(&[0xff]) {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(PRIME);
+        }
+    }
+    hash
+}
+
+/// Allows unused variables in the arguments of a generated function
+/// whose body does not use them.
+pub(crate) fn unuse_sig(mut sig: syn::Signature) -> syn::Signature {
+    for input in &mut sig.inputs {
+        if let syn::FnArg::Typed(typed) = input {
+            typed.attrs.push(syn::Attribute {
+                pound_token:   syn::Token![#](Span::call_site()),
+                style:         syn::AttrStyle::Outer,
+                bracket_token: syn::token::Bracket(Span::call_site()),
+                meta:          syn::Meta::List(syn::MetaList {
+                    path:      syn::parse_quote!(allow),
+                    delimiter: syn::MacroDelimiter::Paren(syn::token::Paren(typed.span())),
+                    tokens:    quote::quote!(unused_variables),
+                }),
+            });
+        }
+    }
+    sig
+}
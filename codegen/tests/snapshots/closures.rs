@@ -0,0 +1,114 @@
+pub struct __PortraitClosureTable {
+    get: ::std::boxed::Box<
+        dyn for<'__portrait_0> Fn(&'__portrait_0 <StoreFns as Store>::Key) -> Option<u32>,
+    >,
+    set: ::std::boxed::Box<dyn FnMut(<StoreFns as Store>::Key, u32)>,
+    name: ::std::boxed::Box<dyn for<'a> Fn(&'a str) -> &'a str>,
+    label: ::std::boxed::Box<dyn Fn() -> &'static str>,
+    first: ::std::boxed::Box<
+        dyn for<'__portrait_0> FnOnce(&'__portrait_0 [u32]) -> &'__portrait_0 u32,
+    >,
+    merge: ::std::boxed::Box<dyn FnOnce(StoreFns) -> StoreFns>,
+}
+impl ::portrait::ClosureTable for StoreFns {
+    type Table = __PortraitClosureTable;
+}
+impl StoreFns {
+    /// Creates a table where every function panics until it is set.
+    pub fn new() -> Self {
+        Self {
+            __portrait_table: __PortraitClosureTable {
+                get: ::std::boxed::Box::new(|_| {
+                    ::core::unimplemented!("`get` is not set")
+                }),
+                set: ::std::boxed::Box::new(|_, _| {
+                    ::core::unimplemented!("`set` is not set")
+                }),
+                name: ::std::boxed::Box::new(|_| {
+                    ::core::unimplemented!("`name` is not set")
+                }),
+                label: ::std::boxed::Box::new(|| {
+                    ::core::unimplemented!("`label` is not set")
+                }),
+                first: ::std::boxed::Box::new(|_| {
+                    ::core::unimplemented!("`first` is not set")
+                }),
+                merge: ::std::boxed::Box::new(|_| {
+                    ::core::unimplemented!("`merge` is not set")
+                }),
+            },
+        }
+    }
+    /// Sets the implementation of `get`.
+    pub fn with_get(
+        mut self,
+        f: impl for<'__portrait_0> Fn(
+            &'__portrait_0 <StoreFns as Store>::Key,
+        ) -> Option<u32> + 'static,
+    ) -> Self {
+        self.__portrait_table.get = ::std::boxed::Box::new(f);
+        self
+    }
+    /// Sets the implementation of `set`.
+    pub fn with_set(
+        mut self,
+        f: impl FnMut(<StoreFns as Store>::Key, u32) + 'static,
+    ) -> Self {
+        self.__portrait_table.set = ::std::boxed::Box::new(f);
+        self
+    }
+    /// Sets the implementation of `name`.
+    pub fn with_name(
+        mut self,
+        f: impl for<'a> Fn(&'a str) -> &'a str + 'static,
+    ) -> Self {
+        self.__portrait_table.name = ::std::boxed::Box::new(f);
+        self
+    }
+    /// Sets the implementation of `label`.
+    pub fn with_label(mut self, f: impl Fn() -> &'static str + 'static) -> Self {
+        self.__portrait_table.label = ::std::boxed::Box::new(f);
+        self
+    }
+    /// Sets the implementation of `first`.
+    pub fn with_first(
+        mut self,
+        f: impl for<'__portrait_0> FnOnce(
+            &'__portrait_0 [u32],
+        ) -> &'__portrait_0 u32 + 'static,
+    ) -> Self {
+        self.__portrait_table.first = ::std::boxed::Box::new(f);
+        self
+    }
+    /// Sets the implementation of `merge`.
+    pub fn with_merge(mut self, f: impl FnOnce(StoreFns) -> StoreFns + 'static) -> Self {
+        self.__portrait_table.merge = ::std::boxed::Box::new(f);
+        self
+    }
+}
+impl ::core::default::Default for StoreFns {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl Store for StoreFns {
+    type Key = String;
+    fn get(&self, key: &Self::Key) -> Option<u32> {
+        (self.__portrait_table.get)(key)
+    }
+    fn set(&mut self, key: Self::Key, value: u32) {
+        (self.__portrait_table.set)(key, value)
+    }
+    fn name<'a>(&self, prefix: &'a str) -> &'a str {
+        (self.__portrait_table.name)(prefix)
+    }
+    fn label(&self) -> &str {
+        (self.__portrait_table.label)()
+    }
+    fn first(self, items: &[u32]) -> &u32 {
+        (self.__portrait_table.first)(items)
+    }
+    fn merge(self, other: Self) -> Self {
+        (self.__portrait_table.merge)(other)
+    }
+}
@@ -0,0 +1,22 @@
+impl Sample for Foo {
+    type Output = ();
+    const SIZE: usize = Default::default();
+    fn new(#[allow(unused_variables)] value: u32) -> Self {
+        Default::default()
+    }
+    fn get(&self, #[allow(unused_variables)] index: usize) -> Option<u32> {
+        Default::default()
+    }
+    fn set(
+        &mut self,
+        #[allow(unused_variables)]
+        index: usize,
+        #[allow(unused_variables)]
+        value: u32,
+    ) -> bool {
+        Default::default()
+    }
+    async fn flush(&mut self) {
+        Default::default()
+    }
+}
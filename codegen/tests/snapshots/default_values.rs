@@ -0,0 +1,22 @@
+impl Sample for Foo {
+    const SIZE: usize = 8;
+    type Output = u8;
+    fn new(#[allow(unused_variables)] value: u32) -> Self {
+        Default::default()
+    }
+    fn get(&self, #[allow(unused_variables)] index: usize) -> Option<u32> {
+        None
+    }
+    fn set(
+        &mut self,
+        #[allow(unused_variables)]
+        index: usize,
+        #[allow(unused_variables)]
+        value: u32,
+    ) -> bool {
+        Default::default()
+    }
+    async fn flush(&mut self) {
+        Default::default()
+    }
+}
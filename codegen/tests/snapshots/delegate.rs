@@ -0,0 +1,19 @@
+impl Sample for Foo {
+    fn new(value: u32) -> Self {
+        Self { inner: Inner::new(value) }
+    }
+    const SIZE: usize = <Inner as Sample>::SIZE;
+    type Output = <Inner as Sample>::Output;
+    #[inline]
+    fn get(&self, index: usize) -> Option<u32> {
+        <Inner as Sample>::get(&self.inner, index)
+    }
+    #[inline]
+    fn set(&mut self, index: usize, value: u32) -> bool {
+        <Inner as Sample>::set(&mut self.inner, index, value)
+    }
+    #[inline]
+    async fn flush(&mut self) {
+        <Inner as Sample>::flush(&mut self.inner).await
+    }
+}
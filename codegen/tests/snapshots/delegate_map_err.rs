@@ -0,0 +1,27 @@
+impl Store for Foo {
+    type Error = FooError;
+    type Key = <Inner as Store>::Key;
+    #[inline]
+    fn get(&self, key: Self::Key) -> Result<u32, Self::Error> {
+        ::core::result::Result::map_err(
+            <Inner as Store>::get(&self.inner, key),
+            Into::into,
+        )
+    }
+    #[inline]
+    fn next(&mut self) -> Option<Result<u32, Self::Error>> {
+        ::core::option::Option::map(
+            <Inner as Store>::next(&mut self.inner),
+            |result| ::core::result::Result::map_err(result, Into::into),
+        )
+    }
+    #[inline]
+    fn poll_flush(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<(), Self::Error>> {
+        ::core::task::Poll::<
+            ::core::result::Result<_, _>,
+        >::map_err(<Inner as Store>::poll_flush(self.inner, cx), Into::into)
+    }
+}
@@ -0,0 +1,24 @@
+impl Shape for AnyShape
+where
+    Circle: Shape,
+    Square: Shape,
+{
+    fn area(&self) -> f64 {
+        match self {
+            Self::Circle { 0: __portrait_self_0, .. } => Shape::area(__portrait_self_0),
+            Self::Square { square: __portrait_self_0, .. } => {
+                Shape::area(__portrait_self_0)
+            }
+        }
+    }
+    fn scale(&mut self, factor: f64) {
+        match self {
+            Self::Circle { 0: __portrait_self_0, .. } => {
+                Shape::scale(__portrait_self_0, factor)
+            }
+            Self::Square { square: __portrait_self_0, .. } => {
+                Shape::scale(__portrait_self_0, factor)
+            }
+        }
+    }
+}
@@ -0,0 +1,21 @@
+impl Shape for Pair
+where
+    Circle: Shape,
+    Square: Shape,
+{
+    fn area(&self) -> f64 {
+        let Self { a: __portrait_self_0, b: __portrait_self_1 } = self;
+        (|a, b| a + b)(Shape::area(__portrait_self_0), Shape::area(__portrait_self_1))
+    }
+    fn scale(&mut self, factor: f64) {
+        let Self { a: __portrait_self_0, b: __portrait_self_1 } = self;
+        Shape::scale(__portrait_self_0, factor);
+        Shape::scale(__portrait_self_1, factor);
+    }
+    fn unit() -> Self {
+        Self {
+            a: Shape::unit(),
+            b: Shape::unit(),
+        }
+    }
+}
@@ -0,0 +1,14 @@
+impl Foo {
+    fn new(value: u32) -> Self {
+        Self(value)
+    }
+    pub fn get(&self, index: usize) -> Option<u32> {
+        <Self as Sample>::get(self, index)
+    }
+    pub fn set(&mut self, index: usize, value: u32) -> bool {
+        <Self as Sample>::set(self, index, value)
+    }
+    pub async fn flush(&mut self) {
+        <Self as Sample>::flush(self).await
+    }
+}
@@ -0,0 +1,8 @@
+impl Logged for Foo {
+    fn get(&self, index: usize) -> String {
+        format!("get({:?})", index)
+    }
+    fn set(&mut self, index: usize, value: u32) -> String {
+        format!("set({:?}, {:?})", index, value)
+    }
+}
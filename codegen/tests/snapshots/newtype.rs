@@ -0,0 +1,27 @@
+impl Merge for Foo {
+    const EMPTY: Self = Self(<Inner as Merge>::EMPTY);
+    #[inline]
+    fn merge(&self, other: &Self) -> Self {
+        Self(<Inner as Merge>::merge(&self.0, &other.0))
+    }
+    #[inline]
+    fn merge_all(items: Vec<Self>) -> Option<Self> {
+        <Inner as Merge>::merge_all(
+                ::core::iter::Iterator::collect::<
+                    ::std::vec::Vec<_>,
+                >(
+                    ::core::iter::Iterator::map(
+                        ::core::iter::IntoIterator::into_iter(items),
+                        |__portrait_newtype_value| __portrait_newtype_value.0,
+                    ),
+                ),
+            )
+            .map(|__portrait_newtype_value| Self(__portrait_newtype_value))
+    }
+    #[inline]
+    async fn load() -> Result<Self, String> {
+        <Inner as Merge>::load()
+            .await
+            .map(|__portrait_newtype_value| Self(__portrait_newtype_value))
+    }
+}
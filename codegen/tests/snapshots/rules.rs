@@ -0,0 +1,21 @@
+impl Sample for Foo {
+    type Output = ();
+    my_filler! {
+        @ ARGS { extra } @ const SIZE : usize;
+    }
+    my_filler! {
+        @ ARGS { extra } @ fn { fn new(value : u32) -> Self } new() (value : u32) ->
+        Self;
+    }
+    my_filler! {
+        @ ARGS { extra } @ fn { fn get(& self, index : usize) -> Option < u32 > }
+        get(self) (index : usize) -> Option < u32 >;
+    }
+    my_filler! {
+        @ ARGS { extra } @ fn { fn set(& mut self, index : usize, value : u32) -> bool }
+        set(self) (index : usize, value : u32) -> bool;
+    }
+    my_filler! {
+        @ ARGS { extra } @ fn { async fn flush(& mut self) } flush(self) () -> ();
+    }
+}
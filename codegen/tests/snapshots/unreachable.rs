@@ -0,0 +1,22 @@
+impl Sample for Never {
+    const SIZE: usize = 0;
+    type Output = Self;
+    fn new(#[allow(unused_variables)] value: u32) -> Self {
+        panic!("uninhabited")
+    }
+    fn get(&self, #[allow(unused_variables)] index: usize) -> Option<u32> {
+        match *self {}
+    }
+    fn set(
+        &mut self,
+        #[allow(unused_variables)]
+        index: usize,
+        #[allow(unused_variables)]
+        value: u32,
+    ) -> bool {
+        match *self {}
+    }
+    async fn flush(&mut self) {
+        match *self {}
+    }
+}
@@ -0,0 +1,44 @@
+//! Declares portraits to be implemented by the integration tests,
+//! which are compiled as a separate crate.
+
+pub mod error {
+    #[derive(Debug, Default, PartialEq)]
+    pub struct Error;
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Config {
+    pub value: u32,
+}
+
+use error::Error;
+
+#[portrait::make(import(crate::error::Error))]
+pub trait Service {
+    fn config(&self) -> crate::Config;
+
+    fn validate(&self, input: u32) -> Option<Error>;
+
+    fn default_config() -> crate::Config { crate::Config { value: 1 } }
+}
+
+pub struct Base;
+
+impl Service for Base {
+    fn config(&self) -> Config { Config { value: 2 } }
+
+    fn validate(&self, input: u32) -> Option<Error> { (input == 0).then_some(Error) }
+}
+
+/// Exported through the `__portraits` module of the derived module.
+#[portrait::make(export = facade)]
+pub trait Scoped {
+    fn scope(&self) -> &'static str;
+}
+
+/// Resolves its relative imports through `module`,
+/// so implementors only need to import the trait.
+#[portrait::make(module = crate, import(super::super::error::Error))]
+pub trait Checked {
+    fn check(&self) -> Option<Error>;
+}
@@ -0,0 +1,21 @@
+//! Portraits declared with `export = facade` can be implemented from other crates.
+
+use portrait_cross_crate_test::Scoped;
+
+struct Defaulted;
+
+#[portrait::fill(portrait::default(for &'static str = "defaulted"))]
+impl Scoped for Defaulted {}
+
+#[portrait::derive(Scoped with portrait::derive_delegate)]
+struct Wrapper(Defaulted);
+
+#[test]
+fn test_fill() {
+    assert_eq!(Defaulted.scope(), "defaulted");
+}
+
+#[test]
+fn test_derive() {
+    assert_eq!(Wrapper(Defaulted).scope(), "defaulted");
+}
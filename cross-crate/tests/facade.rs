@@ -0,0 +1,21 @@
+//! A facade re-exporting a portrait with `pub use dependency::Trait;`.
+
+mod facade {
+    pub use portrait_cross_crate_test::{Scoped, Service};
+}
+
+use facade::{Scoped, Service};
+
+struct Defaulted;
+
+#[portrait::fill(portrait::default)]
+impl Service for Defaulted {}
+
+#[portrait::fill(portrait::default)]
+impl Scoped for Defaulted {}
+
+#[test]
+fn test_facade() {
+    assert_eq!(Defaulted.config(), portrait_cross_crate_test::Config { value: 0 });
+    assert_eq!(Defaulted.scope(), "");
+}
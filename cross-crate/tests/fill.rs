@@ -0,0 +1,39 @@
+#![allow(dead_code)]
+
+use portrait_cross_crate_test::{self as dep, Checked, Service};
+
+/// Shadows `Config` of the trait crate,
+/// which must not be picked up by `crate::Config` in the portrait.
+struct Config;
+
+#[derive(Default)]
+struct Defaulted;
+
+#[portrait::fill(portrait::default)]
+impl Service for Defaulted {}
+
+#[portrait::fill(portrait::default)]
+impl Checked for Defaulted {}
+
+struct Delegated {
+    base: dep::Base,
+}
+
+#[portrait::fill(portrait::delegate(dep::Base; self.base))]
+impl Service for Delegated {}
+
+#[test]
+fn test_default() {
+    assert_eq!(Defaulted.config(), dep::Config { value: 0 });
+    assert_eq!(Defaulted.validate(0), None);
+    assert_eq!(Defaulted::default_config(), dep::Config { value: 0 });
+    assert_eq!(Defaulted.check(), None);
+}
+
+#[test]
+fn test_delegate() {
+    let delegated = Delegated { base: dep::Base };
+    assert_eq!(delegated.config(), dep::Config { value: 2 });
+    assert_eq!(delegated.validate(0), Some(dep::error::Error));
+    assert_eq!(Delegated::default_config(), dep::Config { value: 1 });
+}
@@ -22,7 +22,7 @@ mod def {
 }
 
 mod user {
-    use crate::def::{definition_portrait, Definition};
+    use crate::def::Definition;
 
     struct DefaultUser<T>(T);
 
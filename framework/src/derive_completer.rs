@@ -1,5 +1,7 @@
 extern crate proc_macro;
 
+use std::cell::RefCell;
+
 use proc_macro2::{Span, TokenStream};
 use quote::{quote, ToTokens};
 use syn::parse::Parse;
@@ -79,8 +81,9 @@ pub fn completer_derive_filler2<ArgsT: Parse, GeneratorT: GenerateDerive>(
             args: Self::Args,
             input: &syn::DeriveInput,
         ) -> Result<TokenStream> {
-            let tokens = complete_derive(trait_path, portrait, input, self.0(args))?;
-            Ok(quote!(#tokens))
+            let (tokens, extra_items) =
+                complete_derive_with_extra_items(trait_path, portrait, input, self.0(args))?;
+            Ok(quote!(#(#extra_items)* #tokens))
         }
     }
 
@@ -89,13 +92,44 @@ pub fn completer_derive_filler2<ArgsT: Parse, GeneratorT: GenerateDerive>(
 
 /// Invokes the generator on each unimplemented item
 /// and returns a clone of `impl_block` with the generated items.
+///
+/// Returns an error if the generator emits extra items through [`DeriveContext::emit`];
+/// use [`complete_derive_with_extra_items`] to receive them.
 pub fn complete_derive(
     trait_path: &syn::Path,
     trait_items: &[syn::TraitItem],
     input: &syn::DeriveInput,
-    mut generator: impl GenerateDerive,
+    generator: impl GenerateDerive,
 ) -> syn::Result<syn::ItemImpl> {
-    let ctx = DeriveContext { trait_path, all_trait_items: trait_items, input };
+    let (output, extra_items) =
+        complete_derive_with_extra_items(trait_path, trait_items, input, generator)?;
+    if let Some(item) = extra_items.first() {
+        return Err(syn::Error::new_spanned(
+            item,
+            "the generator emitted items outside the impl block, which are only supported by \
+             `complete_derive_with_extra_items`",
+        ));
+    }
+    Ok(output)
+}
+
+/// Like [`complete_derive`], but also returns the items emitted through [`DeriveContext::emit`]
+/// in the order of emission.
+///
+/// The extra items should be placed next to the impl block.
+pub fn complete_derive_with_extra_items(
+    trait_path: &syn::Path,
+    trait_items: &[syn::TraitItem],
+    input: &syn::DeriveInput,
+    mut generator: impl GenerateDerive,
+) -> syn::Result<(syn::ItemImpl, Vec<syn::Item>)> {
+    let extra_items = RefCell::new(Vec::new());
+    let ctx = DeriveContext {
+        trait_path,
+        all_trait_items: trait_items,
+        input,
+        extra_items: &extra_items,
+    };
 
     let mut generics_params: Vec<_> = input.generics.params.iter().cloned().collect();
     let mut generics_where: Vec<_> = input
@@ -146,8 +180,9 @@ pub fn complete_derive(
     let mut attrs: Vec<_> =
         input.attrs.iter().filter(|attr| attr.path().is_ident("cfg")).cloned().collect();
     generator.extend_attrs(DeriveContext { ..ctx }, &mut attrs)?;
+    generator.finish(DeriveContext { ..ctx })?;
 
-    Ok(syn::ItemImpl {
+    let output = syn::ItemImpl {
         attrs,
         defaultness: None,
         unsafety: None, // TODO support explicit unsafe derive
@@ -167,7 +202,8 @@ pub fn complete_derive(
         self_ty: Box::new(self_ty),
         brace_token: syn::token::Brace::default(),
         items,
-    })
+    };
+    Ok((output, extra_items.into_inner()))
 }
 
 /// Available context parameters passed to generators.
@@ -179,6 +215,16 @@ pub struct DeriveContext<'t> {
     pub all_trait_items: &'t [syn::TraitItem],
     /// The input struct/enum/union.
     pub input:           &'t syn::DeriveInput,
+    extra_items:         &'t RefCell<Vec<syn::Item>>,
+}
+
+impl DeriveContext<'_> {
+    /// Emits an item outside the impl block,
+    /// e.g. a helper struct, a static or a `const _: () = assert!(...);` check.
+    ///
+    /// The filler output is placed in an anonymous `const _: () = { ... };` block,
+    /// so emitted items are only visible to the generated code and other emitted items.
+    pub fn emit(&self, item: syn::Item) { self.extra_items.borrow_mut().push(item); }
 }
 
 /// Generates missing items.
@@ -222,4 +268,8 @@ pub trait GenerateDerive {
     ) -> Result<()> {
         Ok(())
     }
+
+    /// Called after all items are generated,
+    /// e.g. to [emit](DeriveContext::emit) items that depend on all generated items.
+    fn finish(&mut self, _ctx: DeriveContext) -> Result<()> { Ok(()) }
 }
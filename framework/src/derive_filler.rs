@@ -1,7 +1,10 @@
 use proc_macro2::TokenStream;
+use quote::quote;
 use syn::parse::{Parse, ParseStream};
 use syn::Result;
 
+use crate::protocol;
+
 /// Determines how to derive an impl.
 pub trait FillDerive {
     /// The arguments passed to the filler through macros.
@@ -27,10 +30,11 @@ pub fn derive_filler<FillerT: FillDerive>(
     input: TokenStream,
     filler: FillerT,
 ) -> Result<TokenStream> {
-    let Input::<FillerT::Args> { trait_path, portrait, args, input, debug_print } =
+    let Input::<FillerT::Args> { trait_path, portrait, args, input, imports, debug_print } =
         syn::parse2(input)?;
 
     let output = filler.fill(&trait_path, &portrait, args, &input)?;
+    let output = quote!(#imports #output);
 
     if debug_print {
         println!("{output}");
@@ -52,11 +56,14 @@ pub(crate) struct Input<ArgsT> {
     pub(crate) portrait:    Vec<syn::TraitItem>,
     pub(crate) args:        ArgsT,
     pub(crate) input:       syn::DeriveInput,
+    pub(crate) imports:     TokenStream,
     pub(crate) debug_print: bool,
 }
 
 impl<ArgsT: Parse> Parse for Input<ArgsT> {
     fn parse(input: ParseStream) -> Result<Self> {
+        protocol::parse_portrait_version(input)?;
+
         input.parse::<kw::TRAIT_PORTRAIT>()?;
 
         let portrait_braced;
@@ -72,6 +79,10 @@ impl<ArgsT: Parse> Parse for Input<ArgsT> {
             portrait.push(item);
         }
 
+        let imports = protocol::parse_forwarded_imports(input)?;
+
+        protocol::parse_fill_version(input)?;
+
         input.parse::<kw::TRAIT_PATH>()?;
         let trait_path_braced;
         syn::braced!(trait_path_braced in input);
@@ -108,6 +119,13 @@ impl<ArgsT: Parse> Parse for Input<ArgsT> {
             return Err(input.error("trailing tokens in macro input"));
         }
 
-        Ok(Self { trait_path, portrait, args, input: derive_input, debug_print: dpfo.value })
+        Ok(Self {
+            trait_path,
+            portrait,
+            args,
+            input: derive_input,
+            imports,
+            debug_print: dpfo.value,
+        })
     }
 }
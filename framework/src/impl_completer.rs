@@ -1,5 +1,7 @@
 extern crate proc_macro;
 
+use std::cell::RefCell;
+
 use proc_macro2::TokenStream;
 use quote::quote;
 use syn::parse::Parse;
@@ -75,8 +77,9 @@ pub fn completer_impl_filler2<ArgsT: Parse, GeneratorT: GenerateImpl>(
             args: Self::Args,
             item_impl: &syn::ItemImpl,
         ) -> Result<TokenStream> {
-            let tokens = complete_impl(portrait, item_impl, self.0(args))?;
-            Ok(quote!(#tokens))
+            let (tokens, extra_items) =
+                complete_impl_with_extra_items(portrait, item_impl, self.0(args))?;
+            Ok(quote!(#(#extra_items)* #tokens))
         }
     }
 
@@ -85,30 +88,62 @@ pub fn completer_impl_filler2<ArgsT: Parse, GeneratorT: GenerateImpl>(
 
 /// Invokes the generator on each unimplemented item
 /// and returns a clone of `impl_block` with the generated items.
+///
+/// The generated items are appended in the declaration order of the trait.
+///
+/// Returns an error if the generator emits extra items through [`ImplContext::emit`];
+/// use [`complete_impl_with_extra_items`] to receive them.
 pub fn complete_impl(
     trait_items: &[syn::TraitItem],
     impl_block: &syn::ItemImpl,
-    mut generator: impl GenerateImpl,
+    generator: impl GenerateImpl,
 ) -> syn::Result<syn::ItemImpl> {
+    let (output, extra_items) = complete_impl_with_extra_items(trait_items, impl_block, generator)?;
+    if let Some(item) = extra_items.first() {
+        return Err(syn::Error::new_spanned(
+            item,
+            "the generator emitted items outside the impl block, which are only supported by \
+             `complete_impl_with_extra_items`",
+        ));
+    }
+    Ok(output)
+}
+
+/// Like [`complete_impl`], but also returns the items emitted through [`ImplContext::emit`]
+/// in the order of emission.
+///
+/// The extra items should be placed next to the impl block.
+pub fn complete_impl_with_extra_items(
+    trait_items: &[syn::TraitItem],
+    impl_block: &syn::ItemImpl,
+    mut generator: impl GenerateImpl,
+) -> syn::Result<(syn::ItemImpl, Vec<syn::Item>)> {
     let mut output = impl_block.clone();
 
-    let ctx = ImplContext { all_trait_items: trait_items, impl_block };
+    let extra_items = RefCell::new(Vec::new());
+    let ctx = ImplContext { all_trait_items: trait_items, impl_block, extra_items: &extra_items };
 
     let items = subtract_items(trait_items, impl_block)?;
-    for trait_item in items.consts.values() {
-        let impl_item = generator.generate_const(ImplContext { ..ctx }, trait_item)?;
-        output.items.push(syn::ImplItem::Const(impl_item));
-    }
-    for trait_item in items.fns.values() {
-        let impl_item = generator.generate_fn(ImplContext { ..ctx }, trait_item)?;
-        output.items.push(syn::ImplItem::Fn(impl_item));
-    }
-    for trait_item in items.types.values() {
-        let impl_item = generator.generate_type(ImplContext { ..ctx }, trait_item)?;
-        output.items.push(syn::ImplItem::Type(impl_item));
+    // generate in the declaration order of the trait for deterministic output
+    for trait_item in trait_items {
+        let impl_item = match trait_item {
+            syn::TraitItem::Const(item) if items.consts.contains_key(&item.ident) => {
+                syn::ImplItem::Const(generator.generate_const(ImplContext { ..ctx }, item)?)
+            }
+            syn::TraitItem::Fn(item) if items.fns.contains_key(&item.sig.ident) => {
+                syn::ImplItem::Fn(generator.generate_fn(ImplContext { ..ctx }, item)?)
+            }
+            syn::TraitItem::Type(item) if items.types.contains_key(&item.ident) => {
+                syn::ImplItem::Type(generator.generate_type(ImplContext { ..ctx }, item)?)
+            }
+            _ => continue,
+        };
+        output.items.push(impl_item);
     }
 
-    Ok(output)
+    generator.finish(ImplContext { ..ctx })?;
+
+    Ok((output, extra_items.into_inner()))
 }
 
 /// Available context parameters passed to generators.
@@ -118,6 +153,16 @@ pub struct ImplContext<'t> {
     pub all_trait_items: &'t [syn::TraitItem],
     /// The input impl block.
     pub impl_block:      &'t syn::ItemImpl,
+    extra_items:         &'t RefCell<Vec<syn::Item>>,
+}
+
+impl ImplContext<'_> {
+    /// Emits an item outside the impl block,
+    /// e.g. a helper struct, a static or a `const _: () = assert!(...);` check.
+    ///
+    /// The filler output is placed in an anonymous `const _: () = { ... };` block,
+    /// so emitted items are only visible to the generated code and other emitted items.
+    pub fn emit(&self, item: syn::Item) { self.extra_items.borrow_mut().push(item); }
 }
 
 /// Generates missing items.
@@ -139,4 +184,8 @@ pub trait GenerateImpl {
         ctx: ImplContext,
         item: &syn::TraitItemType,
     ) -> Result<syn::ImplItemType>;
+
+    /// Called after all missing items are generated,
+    /// e.g. to [emit](ImplContext::emit) items that depend on all generated items.
+    fn finish(&mut self, _ctx: ImplContext) -> Result<()> { Ok(()) }
 }
@@ -1,7 +1,10 @@
 use proc_macro2::TokenStream;
+use quote::quote;
 use syn::parse::{Parse, ParseStream};
 use syn::Result;
 
+use crate::protocol;
+
 /// Determines how to fill an `impl` block.
 pub trait FillImpl {
     /// The arguments passed to the filler through macros.
@@ -23,9 +26,11 @@ pub trait FillImpl {
 /// If the filler just maps each unimplemented trait item to an impl item statelessly,
 /// use [`completer_impl_filler2`](crate::completer_impl_filler2)/[`proc_macro_impl_filler`](crate::proc_macro_impl_filler) for shorthand.
 pub fn impl_filler<FillerT: FillImpl>(input: TokenStream, filler: FillerT) -> Result<TokenStream> {
-    let Input::<FillerT::Args> { portrait, args, item_impl, debug_print } = syn::parse2(input)?;
+    let Input::<FillerT::Args> { portrait, args, item_impl, imports, debug_print } =
+        syn::parse2(input)?;
 
     let output = filler.fill(&portrait, args, &item_impl)?;
+    let output = quote!(#imports #output);
 
     if debug_print {
         println!("{output}");
@@ -45,11 +50,14 @@ pub(crate) struct Input<ArgsT> {
     pub(crate) portrait:    Vec<syn::TraitItem>,
     pub(crate) args:        ArgsT,
     pub(crate) item_impl:   syn::ItemImpl,
+    pub(crate) imports:     TokenStream,
     pub(crate) debug_print: bool,
 }
 
 impl<ArgsT: Parse> Parse for Input<ArgsT> {
     fn parse(input: ParseStream) -> Result<Self> {
+        protocol::parse_portrait_version(input)?;
+
         input.parse::<kw::TRAIT_PORTRAIT>()?;
 
         let portrait_braced;
@@ -65,6 +73,10 @@ impl<ArgsT: Parse> Parse for Input<ArgsT> {
             portrait.push(item);
         }
 
+        let imports = protocol::parse_forwarded_imports(input)?;
+
+        protocol::parse_fill_version(input)?;
+
         input.parse::<kw::ARGS>()?;
         let args_braced;
         syn::braced!(args_braced in input);
@@ -93,6 +105,6 @@ impl<ArgsT: Parse> Parse for Input<ArgsT> {
             return Err(input.error("trailing tokens in macro input"));
         }
 
-        Ok(Self { portrait, args, item_impl, debug_print: dpfo.value })
+        Ok(Self { portrait, args, item_impl, imports, debug_print: dpfo.value })
     }
 }
@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+use quote::ToTokens;
 use syn::{Error, Result};
 
 /// Shorthand for `TraitItemMap::new().minus(ImplItemMap::new())`.
@@ -45,36 +46,300 @@ impl<'t> TraitItemMap<'t> {
     }
 
     /// Removes the items found in the impl, leaving only unimplemented items.
+    ///
+    /// Impl items not found in the trait are reported together in a single error,
+    /// with suggestions of similarly named trait items.
+    /// Impl items with a different receiver, number of arguments or number of generic parameters
+    /// from the trait are also reported at the impl item,
+    /// before the compiler reports them in the filled output.
     pub fn minus(&mut self, impl_items: &ImplItemMap) -> Result<()> {
-        for (ident, impl_item) in &impl_items.consts {
-            if self.consts.remove(ident).is_none() {
-                return Err(Error::new_spanned(
-                    impl_item,
-                    "no associated constant called {ident} in trait",
-                ));
+        let available = self.available_items();
+
+        let mut errors = Vec::new();
+        minus_kind(
+            &mut self.consts,
+            &impl_items.consts,
+            ItemKind::Const,
+            &available,
+            &mut errors,
+            |_, _| Ok(()),
+        );
+        minus_kind(
+            &mut self.fns,
+            &impl_items.fns,
+            ItemKind::Fn,
+            &available,
+            &mut errors,
+            |trait_item, impl_item| check_fn_sig(&trait_item.sig, &impl_item.sig),
+        );
+        minus_kind(
+            &mut self.types,
+            &impl_items.types,
+            ItemKind::Type,
+            &available,
+            &mut errors,
+            |trait_item, impl_item| {
+                check_generics_count(
+                    &trait_item.ident,
+                    &trait_item.generics,
+                    &impl_item.generics,
+                    &impl_item.ident,
+                )
+            },
+        );
+
+        let mut errors = errors.into_iter();
+        match errors.next() {
+            None => Ok(()),
+            Some(mut error) => {
+                error.extend(errors);
+                Err(error)
             }
         }
+    }
 
-        for (ident, impl_item) in &impl_items.fns {
-            if self.fns.remove(ident).is_none() {
-                return Err(Error::new_spanned(
-                    impl_item,
-                    "no associated function called {ident} in trait",
-                ));
-            }
+    /// Lists the items in the map sorted by kind and name.
+    fn available_items(&self) -> Vec<(ItemKind, syn::Ident)> {
+        let mut items: Vec<_> = self
+            .consts
+            .keys()
+            .map(|ident| (ItemKind::Const, ident.clone()))
+            .chain(self.fns.keys().map(|ident| (ItemKind::Fn, ident.clone())))
+            .chain(self.types.keys().map(|ident| (ItemKind::Type, ident.clone())))
+            .collect();
+        items.sort_by_key(|(kind, ident)| (*kind, ident.to_string()));
+        items
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum ItemKind {
+    Const,
+    Fn,
+    Type,
+}
+
+impl ItemKind {
+    fn description(self) -> &'static str {
+        match self {
+            Self::Const => "associated constant",
+            Self::Fn => "associated function",
+            Self::Type => "associated type",
         }
+    }
+
+    fn keyword(self) -> &'static str {
+        match self {
+            Self::Const => "const",
+            Self::Fn => "fn",
+            Self::Type => "type",
+        }
+    }
+}
 
-        for (ident, impl_item) in &impl_items.types {
-            if self.types.remove(ident).is_none() {
-                return Err(Error::new_spanned(
-                    impl_item,
-                    "no associated type called {ident} in trait",
-                ));
+fn minus_kind<TraitItemT, ImplItemT: ToTokens>(
+    trait_items: &mut HashMap<syn::Ident, TraitItemT>,
+    impl_items: &HashMap<syn::Ident, ImplItemT>,
+    kind: ItemKind,
+    available: &[(ItemKind, syn::Ident)],
+    errors: &mut Vec<Error>,
+    check: impl Fn(&TraitItemT, &ImplItemT) -> Result<()>,
+) {
+    let mut impl_items: Vec<_> = impl_items.iter().collect();
+    impl_items.sort_by_key(|(ident, _)| ident.to_string());
+
+    for (ident, impl_item) in impl_items {
+        match trait_items.remove(ident) {
+            Some(trait_item) => {
+                if let Err(err) = check(&trait_item, impl_item) {
+                    errors.push(err);
+                }
             }
+            None => errors
+                .push(Error::new_spanned(impl_item, missing_item_message(ident, kind, available))),
+        }
+    }
+}
+
+/// Checks that the impl function has the same shape of signature as the trait function.
+fn check_fn_sig(trait_sig: &syn::Signature, impl_sig: &syn::Signature) -> Result<()> {
+    let ident = &impl_sig.ident;
+
+    let trait_receiver = receiver_kind(trait_sig);
+    let impl_receiver = receiver_kind(impl_sig);
+    let receivers_match = match (trait_receiver, impl_receiver) {
+        (ReceiverKind::Typed, receiver) | (receiver, ReceiverKind::Typed) => {
+            receiver != ReceiverKind::None
         }
+        (trait_receiver, impl_receiver) => trait_receiver == impl_receiver,
+    };
+    if !receivers_match {
+        let span: &dyn ToTokens = match impl_sig.receiver() {
+            Some(receiver) => receiver,
+            None => &impl_sig.ident,
+        };
+        return Err(Error::new_spanned(
+            span,
+            format_args!(
+                "`{ident}` has {} in the trait, but {} in the impl",
+                trait_receiver.description(),
+                impl_receiver.description(),
+            ),
+        ));
+    }
+
+    let trait_args = trait_sig.inputs.len() - usize::from(trait_sig.receiver().is_some());
+    let impl_args = impl_sig.inputs.len() - usize::from(impl_sig.receiver().is_some());
+    if trait_args != impl_args {
+        let span: &dyn ToTokens =
+            if impl_sig.inputs.is_empty() { &impl_sig.ident } else { &impl_sig.inputs };
+        return Err(Error::new_spanned(
+            span,
+            format_args!(
+                "`{ident}` takes {trait_args} {} in the trait, but {impl_args} in the impl",
+                plural(trait_args, "argument", "arguments"),
+            ),
+        ));
+    }
+
+    check_generics_count(&trait_sig.ident, &trait_sig.generics, &impl_sig.generics, ident)
+}
+
+/// Checks that the impl item has the same number of type and const parameters as the trait item.
+///
+/// Lifetime parameters are not checked since they may be elided or late-bound.
+fn check_generics_count(
+    trait_ident: &syn::Ident,
+    trait_generics: &syn::Generics,
+    impl_generics: &syn::Generics,
+    impl_ident: &syn::Ident,
+) -> Result<()> {
+    let counts = [
+        (
+            trait_generics.type_params().count(),
+            impl_generics.type_params().count(),
+            "generic type parameter",
+            "generic type parameters",
+        ),
+        (
+            trait_generics.const_params().count(),
+            impl_generics.const_params().count(),
+            "const parameter",
+            "const parameters",
+        ),
+    ];
 
-        Ok(())
+    for (trait_count, impl_count, singular, plural_noun) in counts {
+        if trait_count != impl_count {
+            let span: &dyn ToTokens =
+                if impl_generics.params.is_empty() { impl_ident } else { impl_generics };
+            return Err(Error::new_spanned(
+                span,
+                format_args!(
+                    "`{trait_ident}` has {trait_count} {} in the trait, but {impl_count} in the \
+                     impl",
+                    plural(trait_count, singular, plural_noun),
+                ),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ReceiverKind {
+    None,
+    Value,
+    Ref,
+    RefMut,
+    /// A receiver with an explicit type, e.g. `self: Box<Self>`,
+    /// which is not compared structurally.
+    Typed,
+}
+
+impl ReceiverKind {
+    fn description(self) -> &'static str {
+        match self {
+            Self::None => "no receiver",
+            Self::Value => "a `self` receiver",
+            Self::Ref => "a `&self` receiver",
+            Self::RefMut => "a `&mut self` receiver",
+            Self::Typed => "a typed `self` receiver",
+        }
+    }
+}
+
+fn receiver_kind(sig: &syn::Signature) -> ReceiverKind {
+    match sig.receiver() {
+        None => ReceiverKind::None,
+        Some(receiver) if receiver.colon_token.is_some() => ReceiverKind::Typed,
+        Some(syn::Receiver { reference: None, .. }) => ReceiverKind::Value,
+        Some(syn::Receiver { reference: Some(_), mutability: None, .. }) => ReceiverKind::Ref,
+        Some(syn::Receiver { reference: Some(_), mutability: Some(_), .. }) => ReceiverKind::RefMut,
+    }
+}
+
+fn plural<'s>(count: usize, singular: &'s str, plural: &'s str) -> &'s str {
+    if count == 1 {
+        singular
+    } else {
+        plural
+    }
+}
+
+fn missing_item_message(
+    ident: &syn::Ident,
+    kind: ItemKind,
+    available: &[(ItemKind, syn::Ident)],
+) -> String {
+    let mut message = format!("no {} called `{ident}` in trait", kind.description());
+
+    if let Some(&(other_kind, _)) = available.iter().find(|(_, other)| other == ident) {
+        message += &format!(
+            "\nhelp: `{ident}` is an {} in the trait, implement it as `{} {ident}`",
+            other_kind.description(),
+            other_kind.keyword(),
+        );
+    } else {
+        let name = ident.to_string();
+        let suggestion = available
+            .iter()
+            .filter(|(other_kind, _)| *other_kind == kind)
+            .map(|(_, other)| (edit_distance(&name, &other.to_string()), other))
+            .filter(|&(distance, _)| distance <= (name.chars().count() / 3).max(1))
+            .min_by_key(|&(distance, _)| distance);
+        if let Some((_, other)) = suggestion {
+            message += &format!("\nhelp: did you mean `{other}`?");
+        }
+    }
+
+    if available.is_empty() {
+        message += "\nnote: the trait has no associated items";
+    } else {
+        let list: Vec<_> = available
+            .iter()
+            .map(|(other_kind, other)| format!("`{} {other}`", other_kind.keyword()))
+            .collect();
+        message += &format!("\nnote: the trait has the following items: {}", list.join(", "));
+    }
+
+    message
+}
+
+/// Computes the Levenshtein distance between two strings.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, &b_char) in b.iter().enumerate() {
+            let substitution = prev[j] + usize::from(a_char != b_char);
+            current.push(substitution.min(prev[j + 1] + 1).min(current[j] + 1));
+        }
+        prev = current;
     }
+    prev[b.len()]
 }
 
 /// Indexes items in an impl block by namespaced identifier.
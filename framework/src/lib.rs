@@ -15,14 +15,26 @@ pub use no_args::NoArgs;
 
 mod impl_completer;
 pub use impl_completer::{
-    complete_impl, completer_impl_filler, completer_impl_filler2, GenerateImpl, ImplContext,
+    complete_impl, complete_impl_with_extra_items, completer_impl_filler, completer_impl_filler2,
+    GenerateImpl, ImplContext,
 };
 
 mod derive_completer;
 pub use derive_completer::{
-    complete_derive, completer_derive_filler, completer_derive_filler2, DeriveContext,
-    GenerateDerive,
+    complete_derive, complete_derive_with_extra_items, completer_derive_filler,
+    completer_derive_filler2, DeriveContext, GenerateDerive,
 };
 
 mod item_map;
 pub use item_map::{subtract_items, ImplItemMap, TraitItemMap};
+
+pub mod protocol;
+pub use protocol::{MIN_PROTOCOL_VERSION, PROTOCOL_VERSION};
+
+pub mod rules;
+
+#[cfg(feature = "testing")]
+pub mod testing;
+
+mod return_kind;
+pub use return_kind::{contains_self, is_self, is_self_or_ref, ReturnKind};
@@ -0,0 +1,119 @@
+//! Versioning of the token protocol between portrait macros and fillers.
+//!
+//! The `macro_rules!` generated by `#[portrait::make]` tags the trait portrait
+//! with `PORTRAIT_PROTOCOL {N}`,
+//! and `#[portrait::fill]`/`#[portrait::derive]` tag their invocation with `FILL_PROTOCOL {N}`.
+//! Fillers built on this crate check both versions before parsing the rest of the input,
+//! so that mismatched crate versions result in a readable error
+//! instead of an arbitrary parse error.
+//! Input without a version tag was generated before versioning was introduced
+//! and is treated as version 0.
+//!
+//! `#[portrait::fill]`/`#[portrait::derive]` pass the imports of the portrait module
+//! to the macro as `@IMPORTS { use path::to::imports::*; }`,
+//! which is consumed by the macro if it does not require the portrait module.
+//! Macros generated by older versions of `#[portrait::make]` forward it to the filler
+//! as `IMPORTS {...}` after the trait portrait instead,
+//! in which case the filler emits the imports together with its output.
+
+use proc_macro2::{Span, TokenStream};
+use syn::parse::ParseStream;
+use syn::Result;
+
+/// The protocol version generated and understood by this version of portrait.
+pub const PROTOCOL_VERSION: u32 = 1;
+
+/// The oldest protocol version still understood by fillers built on this crate.
+pub const MIN_PROTOCOL_VERSION: u32 = 0;
+
+mod kw {
+    syn::custom_keyword!(PORTRAIT_PROTOCOL);
+    syn::custom_keyword!(FILL_PROTOCOL);
+    syn::custom_keyword!(IMPORTS);
+}
+
+/// Parses the optional `PORTRAIT_PROTOCOL {N}` tag emitted by `#[portrait::make]`
+/// and checks that it is compatible with this crate.
+pub(crate) fn parse_portrait_version(input: ParseStream) -> Result<()> {
+    let version = if input.peek(kw::PORTRAIT_PROTOCOL) {
+        input.parse::<kw::PORTRAIT_PROTOCOL>()?;
+        parse_version_braced(input)?
+    } else {
+        0
+    };
+
+    check_version(
+        version,
+        "the portrait of this trait",
+        "the `portrait` dependency of the crate that declares the trait",
+    )
+}
+
+/// Parses the optional `FILL_PROTOCOL {N}` tag emitted by `#[portrait::fill]`/`#[portrait::derive]`
+/// and checks that it is compatible with this crate.
+pub(crate) fn parse_fill_version(input: ParseStream) -> Result<()> {
+    let version = if input.peek(kw::FILL_PROTOCOL) {
+        input.parse::<kw::FILL_PROTOCOL>()?;
+        parse_version_braced(input)?
+    } else {
+        0
+    };
+
+    check_version(
+        version,
+        "the portrait attribute invoking this filler",
+        "the `portrait` dependency of this crate",
+    )
+}
+
+/// Parses the `IMPORTS {...}` forwarded by macros that do not consume `@IMPORTS`.
+///
+/// Returns the imports to be emitted before the filler output.
+pub(crate) fn parse_forwarded_imports(input: ParseStream) -> Result<TokenStream> {
+    if input.peek(kw::IMPORTS) {
+        input.parse::<kw::IMPORTS>()?;
+        let inner;
+        syn::braced!(inner in input);
+        inner.parse()
+    } else {
+        Ok(TokenStream::new())
+    }
+}
+
+fn parse_version_braced(input: ParseStream) -> Result<u32> {
+    let inner;
+    syn::braced!(inner in input);
+    let version: syn::LitInt = inner.parse()?;
+    if !inner.is_empty() {
+        return Err(inner.error("trailing tokens after protocol version"));
+    }
+    version.base10_parse()
+}
+
+fn check_version(version: u32, source: &str, outdated_source_crate: &str) -> Result<()> {
+    if version > PROTOCOL_VERSION {
+        return Err(syn::Error::new(
+            Span::call_site(),
+            format_args!(
+                "{source} uses portrait protocol version {version}, but this filler only supports \
+                 versions {MIN_PROTOCOL_VERSION} to {PROTOCOL_VERSION}. Upgrade the crate that \
+                 provides the filler macro to a version built on a newer `portrait-framework`."
+            ),
+        ));
+    }
+
+    #[allow(clippy::absurd_extreme_comparisons)]
+    // the minimum version is raised on breaking changes
+    if version < MIN_PROTOCOL_VERSION {
+        return Err(syn::Error::new(
+            Span::call_site(),
+            format_args!(
+                "{source} uses portrait protocol version {version}, but this filler only supports \
+                 versions {MIN_PROTOCOL_VERSION} to {PROTOCOL_VERSION}. Upgrade \
+                 {outdated_source_crate}."
+            ),
+        ));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,111 @@
+use syn::visit::{self, Visit};
+
+/// Classification of the return type of an associated function.
+///
+/// Fillers can use this to decide how the value returned by a generated function
+/// should be produced or forwarded,
+/// e.g. whether a delegation result has to be `.await`ed
+/// or whether a `Self` value has to be reconstructed.
+#[non_exhaustive]
+pub enum ReturnKind<'t> {
+    /// The function has no explicit return type, i.e. it returns `()`.
+    Unit,
+    /// The return type neither involves `Self` nor is a return-position `impl Trait`.
+    Plain(&'t syn::Type),
+    /// The return type is exactly `Self`.
+    SelfType(&'t syn::Type),
+    /// The return type contains `Self` in a nested position,
+    /// e.g. `&Self`, `Option<Self>` or `Vec<Self>`.
+    ///
+    /// Associated type projections like `Self::Item` do not count as `Self`.
+    ContainsSelf(&'t syn::Type),
+    /// The return type is a return-position `impl Trait`, e.g. `impl Iterator<Item = u32>`.
+    ImplTrait(&'t syn::TypeImplTrait),
+    /// The function is an `async fn`.
+    /// The inner kind classifies the output type of the returned future.
+    Async(Box<ReturnKind<'t>>),
+}
+
+impl<'t> ReturnKind<'t> {
+    /// Classifies the return type of a function signature.
+    pub fn of(sig: &'t syn::Signature) -> Self {
+        let kind = match &sig.output {
+            syn::ReturnType::Default => Self::Unit,
+            syn::ReturnType::Type(_, ty) => Self::of_type(ty),
+        };
+
+        if sig.asyncness.is_some() {
+            Self::Async(Box::new(kind))
+        } else {
+            kind
+        }
+    }
+
+    fn of_type(ty: &'t syn::Type) -> Self {
+        match ty {
+            syn::Type::Paren(paren) => Self::of_type(&paren.elem),
+            syn::Type::Group(group) => Self::of_type(&group.elem),
+            syn::Type::Tuple(tuple) if tuple.elems.is_empty() => Self::Unit,
+            syn::Type::ImplTrait(impl_trait) => Self::ImplTrait(impl_trait),
+            _ if is_self(ty) => Self::SelfType(ty),
+            _ if contains_self(ty) => Self::ContainsSelf(ty),
+            _ => Self::Plain(ty),
+        }
+    }
+
+    /// Whether calling the function returns a future,
+    /// i.e. it is an `async fn` or returns `impl Future`.
+    pub fn is_future(&self) -> bool {
+        match self {
+            Self::Async(_) => true,
+            Self::ImplTrait(impl_trait) => impl_trait.bounds.iter().any(|bound| {
+                matches!(bound, syn::TypeParamBound::Trait(bound)
+                    if bound.path.segments.last().is_some_and(|segment| segment.ident == "Future"))
+            }),
+            _ => false,
+        }
+    }
+}
+
+/// Whether the type is exactly `Self`.
+pub fn is_self(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Paren(paren) => is_self(&paren.elem),
+        syn::Type::Group(group) => is_self(&group.elem),
+        syn::Type::Path(path) => path.qself.is_none() && path.path.is_ident("Self"),
+        _ => false,
+    }
+}
+
+/// Whether the type is exactly `Self` or a reference to `Self`,
+/// i.e. `Self`, `&Self` or `&mut Self` with any lifetime.
+///
+/// Nested references like `&&Self` are not matched.
+pub fn is_self_or_ref(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Paren(paren) => is_self_or_ref(&paren.elem),
+        syn::Type::Group(group) => is_self_or_ref(&group.elem),
+        syn::Type::Reference(reference) => is_self(&reference.elem),
+        _ => is_self(ty),
+    }
+}
+
+/// Whether the type is `Self` or mentions `Self` anywhere within it.
+///
+/// Associated type projections like `Self::Item` do not count as `Self`.
+pub fn contains_self(ty: &syn::Type) -> bool {
+    struct Visitor(bool);
+
+    impl<'ast> Visit<'ast> for Visitor {
+        fn visit_type_path(&mut self, ty: &'ast syn::TypePath) {
+            if ty.qself.is_none() && ty.path.is_ident("Self") {
+                self.0 = true;
+            }
+            visit::visit_type_path(self, ty);
+        }
+    }
+
+    let mut visitor = Visitor(false);
+    visitor.visit_type(ty);
+    visitor.0
+}
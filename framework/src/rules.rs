@@ -0,0 +1,107 @@
+//! The simplified item-by-item form of a portrait for `macro_rules!` fillers.
+//!
+//! Declarative macros cannot easily parse arbitrary trait items,
+//! so each trait item is pre-classified into one of the following forms
+//! and passed to the filler macro in a separate invocation.
+//! These forms are part of the stable public interface of portrait
+//! and do not change with the [protocol version](crate::protocol).
+//!
+//! ```text
+//! $filler! {
+//!     @ARGS { $($args:tt)* }
+//!     @fn $(#[$attr:meta])* { $($sig:tt)* } $name:ident ($($self_:ident)?) ($($arg:ident: $arg_ty:ty),*) -> $ret:ty;
+//! }
+//! $filler! {
+//!     @ARGS { $($args:tt)* }
+//!     @const $(#[$attr:meta])* $name:ident: $ty:ty;
+//! }
+//! $filler! {
+//!     @ARGS { $($args:tt)* }
+//!     @type $(#[$attr:meta])* $name:ident [$($generics:tt)*] where [$($where:tt)*];
+//! }
+//! ```
+//!
+//! - `$args` are the arguments passed by the user to the filler.
+//! - `$attr` are the attributes on the trait item, excluding `#[portrait(...)]` attributes.
+//! - `$sig` is the complete signature of the function, e.g. `async fn foo<T>(&self, x: T) -> u32 where T: Copy`,
+//!   which can be followed by a block to implement the function.
+//!   Argument patterns are replaced with identifiers.
+//! - `$self_` is the `self` keyword of the receiver, if any.
+//!   It must be used in place of a literal `self` in the function body due to macro hygiene.
+//! - `$arg` and `$arg_ty` are the names and types of the non-receiver arguments.
+//! - `$ret` is the declared return type, or `()` if the function does not declare one.
+//!   For `async fn`s, this is the output type of the future.
+//! - `$generics` and `$where` are the generic parameters and where predicates of the associated type
+//!   without the enclosing `<>`/`where`.
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::spanned::Spanned;
+
+/// Encodes a trait item in the simplified form, without the `@ARGS` part.
+///
+/// Returns `None` for trait items that are neither constants, functions nor types.
+pub fn encode_trait_item(item: &syn::TraitItem) -> Option<TokenStream> {
+    match item {
+        syn::TraitItem::Const(item) => {
+            let attrs = filter_attrs(&item.attrs);
+            let ident = &item.ident;
+            let ty = &item.ty;
+            Some(quote!(@const #(#attrs)* #ident: #ty;))
+        }
+        syn::TraitItem::Fn(item) => {
+            let attrs = filter_attrs(&item.attrs);
+            let mut sig = item.sig.clone();
+
+            let mut self_ = None;
+            let mut args = Vec::new();
+            for (ord, input) in sig.inputs.iter_mut().enumerate() {
+                match input {
+                    syn::FnArg::Receiver(receiver) => {
+                        if receiver.reference.is_none() {
+                            receiver.mutability = None;
+                        }
+                        self_ = Some(receiver.self_token);
+                    }
+                    syn::FnArg::Typed(pat_ty) => {
+                        let ident = match &*pat_ty.pat {
+                            syn::Pat::Ident(pat_ident) if pat_ident.subpat.is_none() => {
+                                pat_ident.ident.clone()
+                            }
+                            pat => format_ident!("__portrait_arg_{ord}", span = pat.span()),
+                        };
+                        *pat_ty.pat = syn::Pat::Ident(syn::PatIdent {
+                            attrs:      Vec::new(),
+                            by_ref:     None,
+                            mutability: None,
+                            ident:      ident.clone(),
+                            subpat:     None,
+                        });
+                        let ty = &pat_ty.ty;
+                        args.push(quote!(#ident: #ty));
+                    }
+                }
+            }
+
+            let ident = &sig.ident;
+            let ret = match &sig.output {
+                syn::ReturnType::Default => quote!(()),
+                syn::ReturnType::Type(_, ty) => quote!(#ty),
+            };
+
+            Some(quote!(@fn #(#attrs)* { #sig } #ident (#self_) (#(#args),*) -> #ret;))
+        }
+        syn::TraitItem::Type(item) => {
+            let attrs = filter_attrs(&item.attrs);
+            let ident = &item.ident;
+            let params = &item.generics.params;
+            let predicates = item.generics.where_clause.as_ref().map(|clause| &clause.predicates);
+            Some(quote!(@type #(#attrs)* #ident [#params] where [#predicates];))
+        }
+        _ => None,
+    }
+}
+
+fn filter_attrs(attrs: &[syn::Attribute]) -> impl Iterator<Item = &syn::Attribute> {
+    attrs.iter().filter(|attr| !attr.path().is_ident("portrait"))
+}
@@ -0,0 +1,196 @@
+//! Utilities for testing fillers without going through the compiler.
+//!
+//! The `expand_*` functions construct the same input that `#[portrait::fill]`/`#[portrait::derive]`
+//! would pass to the filler through the portrait macro,
+//! so that the filler output can be inspected directly.
+//! Combine them with [`pretty_print`] and [`assert_snapshot`]
+//! to compare the output against a checked-in file:
+//!
+//! ```
+//! # use portrait_framework::{ImplContext, GenerateImpl, NoArgs};
+//! # use portrait_framework::testing::{expand_impl_completer, pretty_print};
+//! # use quote::quote;
+//! struct Generator(NoArgs);
+//! impl GenerateImpl for Generator {
+//!     fn generate_const(&mut self, _: ImplContext, item: &syn::TraitItemConst) -> syn::Result<syn::ImplItemConst> {
+//!         let ident = &item.ident;
+//!         let ty = &item.ty;
+//!         Ok(syn::parse_quote!(const #ident: #ty = 0;))
+//!     }
+//!     fn generate_fn(&mut self, _: ImplContext, item: &syn::TraitItemFn) -> syn::Result<syn::ImplItemFn> {
+//!         let sig = &item.sig;
+//!         Ok(syn::parse_quote!(#sig { todo!() }))
+//!     }
+//!     fn generate_type(&mut self, _: ImplContext, item: &syn::TraitItemType) -> syn::Result<syn::ImplItemType> {
+//!         let ident = &item.ident;
+//!         Ok(syn::parse_quote!(type #ident = ();))
+//!     }
+//! }
+//!
+//! let output = expand_impl_completer(
+//!     Generator,
+//!     quote!(trait Foo { const BAR: u32; }),
+//!     quote!(),
+//!     quote!(impl Foo for Qux {}),
+//! )
+//! .unwrap();
+//! assert_eq!(pretty_print(&output), "impl Foo for Qux {\n    const BAR: u32 = 0;\n}\n");
+//! // portrait_framework::testing::assert_snapshot("tests/snapshots/foo.rs", &pretty_print(&output));
+//! ```
+
+use std::path::Path;
+use std::{env, fs};
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::parse::Parse;
+use syn::Result;
+
+use crate::{
+    completer_derive_filler2, completer_impl_filler2, derive_filler, impl_filler, FillDerive,
+    FillImpl, GenerateDerive, GenerateImpl, PROTOCOL_VERSION,
+};
+
+/// The environment variable that causes [`assert_snapshot`] to overwrite snapshots.
+pub const UPDATE_SNAPSHOTS_ENV: &str = "PORTRAIT_UPDATE_SNAPSHOTS";
+
+/// Runs an impl filler on the trait `item_trait` and the impl block `item_impl`.
+///
+/// `args` are the tokens passed to the filler in `#[portrait::fill(filler(args))]`.
+pub fn expand_impl_filler<FillerT: FillImpl>(
+    filler: FillerT,
+    item_trait: TokenStream,
+    args: TokenStream,
+    item_impl: TokenStream,
+) -> Result<TokenStream> {
+    impl_filler(impl_input(item_trait, args, item_impl)?, filler)
+}
+
+/// Runs an impl generator through [`complete_impl`](crate::complete_impl)
+/// on the trait `item_trait` and the impl block `item_impl`.
+///
+/// `ctor` constructs the generator from the parsed `args`.
+pub fn expand_impl_completer<ArgsT: Parse, GeneratorT: GenerateImpl>(
+    ctor: fn(ArgsT) -> GeneratorT,
+    item_trait: TokenStream,
+    args: TokenStream,
+    item_impl: TokenStream,
+) -> Result<TokenStream> {
+    completer_impl_filler2(impl_input(item_trait, args, item_impl)?, ctor)
+}
+
+/// Runs a derive filler on the trait `item_trait` and the derived item `input`.
+///
+/// The trait is referenced by its identifier in the generated impl.
+pub fn expand_derive_filler<FillerT: FillDerive>(
+    filler: FillerT,
+    item_trait: TokenStream,
+    args: TokenStream,
+    input: TokenStream,
+) -> Result<TokenStream> {
+    derive_filler(derive_input(item_trait, args, input)?, filler)
+}
+
+/// Runs a derive generator through [`complete_derive`](crate::complete_derive)
+/// on the trait `item_trait` and the derived item `input`.
+///
+/// `ctor` constructs the generator from the parsed `args`.
+pub fn expand_derive_completer<ArgsT: Parse, GeneratorT: GenerateDerive>(
+    ctor: fn(ArgsT) -> GeneratorT,
+    item_trait: TokenStream,
+    args: TokenStream,
+    input: TokenStream,
+) -> Result<TokenStream> {
+    completer_derive_filler2(derive_input(item_trait, args, input)?, ctor)
+}
+
+fn impl_input(
+    item_trait: TokenStream,
+    args: TokenStream,
+    item_impl: TokenStream,
+) -> Result<TokenStream> {
+    let portrait = portrait_tokens(item_trait)?;
+    let version = proc_macro2::Literal::u32_unsuffixed(PROTOCOL_VERSION);
+    Ok(quote! {
+        PORTRAIT_PROTOCOL { #version }
+        #portrait
+        FILL_PROTOCOL { #version }
+        ARGS { #args }
+        IMPL { #item_impl }
+        DEBUG_PRINT_FILLER_OUTPUT { false }
+    })
+}
+
+fn derive_input(
+    item_trait: TokenStream,
+    args: TokenStream,
+    input: TokenStream,
+) -> Result<TokenStream> {
+    let trait_ident = syn::parse2::<syn::ItemTrait>(item_trait.clone())?.ident;
+    let portrait = portrait_tokens(item_trait)?;
+    let version = proc_macro2::Literal::u32_unsuffixed(PROTOCOL_VERSION);
+    Ok(quote! {
+        PORTRAIT_PROTOCOL { #version }
+        #portrait
+        FILL_PROTOCOL { #version }
+        TRAIT_PATH { #trait_ident }
+        ARGS { #args }
+        INPUT { #input }
+        DEBUG_PRINT_FILLER_OUTPUT { false }
+    })
+}
+
+/// Generates the `TRAIT_PORTRAIT` part of the filler input like `#[portrait::make]`.
+fn portrait_tokens(item_trait: TokenStream) -> Result<TokenStream> {
+    let item_trait: syn::ItemTrait = syn::parse2(item_trait)?;
+    let items = &item_trait.items;
+    Ok(quote!(TRAIT_PORTRAIT { #({#items})* }))
+}
+
+/// Formats filler output as Rust source code.
+///
+/// Output that cannot be parsed as a list of items is printed as raw tokens.
+pub fn pretty_print(tokens: &TokenStream) -> String {
+    match syn::parse2::<syn::File>(tokens.clone()) {
+        Ok(file) => prettyplease::unparse(&file),
+        Err(_) => format!("{tokens}\n"),
+    }
+}
+
+/// Compares `actual` against the snapshot file at `path`.
+///
+/// Relative paths are resolved from the current directory,
+/// which is the package root when running `cargo test`.
+/// If the environment variable [`PORTRAIT_UPDATE_SNAPSHOTS`](UPDATE_SNAPSHOTS_ENV) is set,
+/// the snapshot file is overwritten with `actual` instead.
+///
+/// # Panics
+/// Panics if the snapshot file is missing or differs from `actual`.
+pub fn assert_snapshot(path: impl AsRef<Path>, actual: &str) {
+    let path = path.as_ref();
+
+    if env::var_os(UPDATE_SNAPSHOTS_ENV).is_some() {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).expect("failed to create snapshot directory");
+        }
+        fs::write(path, actual).expect("failed to write snapshot");
+        return;
+    }
+
+    let expected = match fs::read_to_string(path) {
+        Ok(expected) => expected,
+        Err(err) => panic!(
+            "cannot read snapshot {}: {err}\nRerun with {UPDATE_SNAPSHOTS_ENV}=1 to create it. \
+             Actual output:\n{actual}",
+            path.display()
+        ),
+    };
+
+    if expected != actual {
+        panic!(
+            "snapshot {} does not match the output\nRerun with {UPDATE_SNAPSHOTS_ENV}=1 to update \
+             it.\n--- expected\n{expected}\n--- actual\n{actual}",
+            path.display()
+        );
+    }
+}
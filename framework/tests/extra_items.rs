@@ -0,0 +1,178 @@
+use portrait_framework::{
+    complete_derive_with_extra_items, complete_impl, complete_impl_with_extra_items, DeriveContext,
+    GenerateDerive, GenerateImpl, ImplContext,
+};
+use quote::{format_ident, quote, ToTokens};
+
+/// Emits a static per generated function and the number of functions at the end.
+#[derive(Default)]
+struct Generator {
+    fns: usize,
+}
+
+impl GenerateImpl for Generator {
+    fn generate_const(
+        &mut self,
+        ctx: ImplContext,
+        item: &syn::TraitItemConst,
+    ) -> syn::Result<syn::ImplItemConst> {
+        let ident = &item.ident;
+        let ty = &item.ty;
+        ctx.emit(syn::parse_quote!(
+            const _: () = assert!(::core::mem::size_of::<#ty>() > 0);
+        ));
+        Ok(syn::parse_quote!(const #ident: #ty = 0;))
+    }
+
+    fn generate_fn(
+        &mut self,
+        ctx: ImplContext,
+        item: &syn::TraitItemFn,
+    ) -> syn::Result<syn::ImplItemFn> {
+        self.fns += 1;
+        let sig = &item.sig;
+        let name = sig.ident.to_string();
+        let static_ident = format_ident!("NAME_{}", name.to_uppercase());
+        ctx.emit(syn::parse_quote!(static #static_ident: &str = #name;));
+        Ok(syn::parse_quote!(#sig { #static_ident }))
+    }
+
+    fn generate_type(
+        &mut self,
+        _: ImplContext,
+        item: &syn::TraitItemType,
+    ) -> syn::Result<syn::ImplItemType> {
+        let ident = &item.ident;
+        Ok(syn::parse_quote!(type #ident = ();))
+    }
+
+    fn finish(&mut self, ctx: ImplContext) -> syn::Result<()> {
+        let fns = self.fns;
+        ctx.emit(syn::parse_quote!(const FNS: usize = #fns;));
+        Ok(())
+    }
+}
+
+impl GenerateDerive for Generator {
+    fn generate_const(
+        &mut self,
+        _: DeriveContext,
+        item: &syn::TraitItemConst,
+    ) -> syn::Result<syn::ImplItemConst> {
+        let ident = &item.ident;
+        let ty = &item.ty;
+        Ok(syn::parse_quote!(const #ident: #ty = 0;))
+    }
+
+    fn generate_fn(
+        &mut self,
+        ctx: DeriveContext,
+        item: &syn::TraitItemFn,
+    ) -> syn::Result<syn::ImplItemFn> {
+        let sig = &item.sig;
+        let name = format!("{}::{}", ctx.input.ident, sig.ident);
+        let static_ident = format_ident!("NAME_{}", sig.ident.to_string().to_uppercase());
+        ctx.emit(syn::parse_quote!(static #static_ident: &str = #name;));
+        Ok(syn::parse_quote!(#sig { #static_ident }))
+    }
+
+    fn generate_type(
+        &mut self,
+        _: DeriveContext,
+        item: &syn::TraitItemType,
+    ) -> syn::Result<syn::ImplItemType> {
+        let ident = &item.ident;
+        Ok(syn::parse_quote!(type #ident = ();))
+    }
+}
+
+fn item_trait() -> syn::ItemTrait {
+    syn::parse_quote! {
+        trait Named {
+            const ID: u32;
+            fn name(&self) -> &'static str;
+            fn kind(&self) -> &'static str;
+        }
+    }
+}
+
+fn to_strings(items: &[syn::Item]) -> Vec<String> {
+    items.iter().map(|item| item.to_token_stream().to_string()).collect()
+}
+
+#[test]
+fn impl_emits_in_order() {
+    let item_impl: syn::ItemImpl = syn::parse_quote! {
+        impl Named for Foo {
+            fn kind(&self) -> &'static str { "foo" }
+        }
+    };
+
+    let (output, extra_items) =
+        complete_impl_with_extra_items(&item_trait().items, &item_impl, Generator::default())
+            .unwrap();
+
+    assert_eq!(output.items.len(), 3);
+    assert_eq!(
+        to_strings(&extra_items),
+        [
+            quote!(
+                const _: () = assert!(::core::mem::size_of::<u32>() > 0);
+            )
+            .to_string(),
+            quote!(
+                static NAME_NAME: &str = "name";
+            )
+            .to_string(),
+            quote!(
+                const FNS: usize = 1usize;
+            )
+            .to_string(),
+        ]
+    );
+}
+
+#[test]
+fn derive_emits() {
+    let input: syn::DeriveInput = syn::parse_quote!(
+        struct Foo;
+    );
+    let trait_path: syn::Path = syn::parse_quote!(Named);
+
+    let (output, extra_items) = complete_derive_with_extra_items(
+        &trait_path,
+        &item_trait().items,
+        &input,
+        Generator::default(),
+    )
+    .unwrap();
+
+    assert_eq!(output.items.len(), 3);
+    assert_eq!(
+        to_strings(&extra_items),
+        [
+            quote!(
+                static NAME_NAME: &str = "Foo::name";
+            )
+            .to_string(),
+            quote!(
+                static NAME_KIND: &str = "Foo::kind";
+            )
+            .to_string(),
+        ]
+    );
+}
+
+#[test]
+fn complete_impl_rejects_extra_items() {
+    let item_impl: syn::ItemImpl = syn::parse_quote!(impl Named for Foo {});
+
+    let Err(err) = complete_impl(&item_trait().items, &item_impl, Generator::default()) else {
+        panic!("extra items should be rejected")
+    };
+    assert_eq!(
+        err.to_string(),
+        "the generator emitted items outside the impl block, which are only supported by \
+         `complete_impl_with_extra_items`"
+    );
+}
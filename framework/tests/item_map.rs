@@ -0,0 +1,159 @@
+use portrait_framework::subtract_items;
+
+fn subtract_error(trait_items: syn::ItemTrait, item_impl: syn::ItemImpl) -> Vec<String> {
+    let Err(err) = subtract_items(&trait_items.items, &item_impl) else {
+        panic!("impl items should not match the trait")
+    };
+    err.into_iter().map(|err| err.to_string()).collect()
+}
+
+#[test]
+fn missing_item_suggestion() {
+    let messages = subtract_error(
+        syn::parse_quote! {
+            trait Foo {
+                const BAR: u32;
+                fn foo_bar(&self);
+                type Qux;
+            }
+        },
+        syn::parse_quote! {
+            impl Foo for Bar {
+                fn foo_baz(&self) {}
+            }
+        },
+    );
+    assert_eq!(
+        messages,
+        ["no associated function called `foo_baz` in trait\nhelp: did you mean `foo_bar`?\nnote: \
+          the trait has the following items: `const BAR`, `fn foo_bar`, `type Qux`"]
+    );
+}
+
+#[test]
+fn wrong_item_kind() {
+    let messages = subtract_error(
+        syn::parse_quote! {
+            trait Foo {
+                const BAR: u32;
+            }
+        },
+        syn::parse_quote! {
+            impl Foo for Bar {
+                fn BAR() {}
+            }
+        },
+    );
+    assert_eq!(
+        messages,
+        ["no associated function called `BAR` in trait\nhelp: `BAR` is an associated constant in \
+          the trait, implement it as `const BAR`\nnote: the trait has the following items: \
+          `const BAR`"]
+    );
+}
+
+#[test]
+fn combined_errors() {
+    let messages = subtract_error(
+        syn::parse_quote! {
+            trait Foo {}
+        },
+        syn::parse_quote! {
+            impl Foo for Bar {
+                const A: u32 = 1;
+                fn b() {}
+                type C = ();
+            }
+        },
+    );
+    assert_eq!(
+        messages,
+        [
+            "no associated constant called `A` in trait\nnote: the trait has no associated items",
+            "no associated function called `b` in trait\nnote: the trait has no associated items",
+            "no associated type called `C` in trait\nnote: the trait has no associated items",
+        ]
+    );
+}
+
+#[test]
+fn receiver_mismatch() {
+    let messages = subtract_error(
+        syn::parse_quote! {
+            trait Foo {
+                fn foo(&self);
+            }
+        },
+        syn::parse_quote! {
+            impl Foo for Bar {
+                fn foo(&mut self) {}
+            }
+        },
+    );
+    assert_eq!(
+        messages,
+        ["`foo` has a `&self` receiver in the trait, but a `&mut self` receiver in the impl"]
+    );
+}
+
+#[test]
+fn arity_mismatch() {
+    let messages = subtract_error(
+        syn::parse_quote! {
+            trait Foo {
+                fn foo(&self, a: u32);
+            }
+        },
+        syn::parse_quote! {
+            impl Foo for Bar {
+                fn foo(&self, a: u32, b: u32) {}
+            }
+        },
+    );
+    assert_eq!(messages, ["`foo` takes 1 argument in the trait, but 2 in the impl"]);
+}
+
+#[test]
+fn generics_mismatch() {
+    let messages = subtract_error(
+        syn::parse_quote! {
+            trait Foo {
+                fn foo<T>(&self, t: T);
+                type Bar<const N: usize>;
+            }
+        },
+        syn::parse_quote! {
+            impl Foo for Bar {
+                fn foo(&self, t: u32) {}
+                type Bar = ();
+            }
+        },
+    );
+    assert_eq!(
+        messages,
+        [
+            "`foo` has 1 generic type parameter in the trait, but 0 in the impl",
+            "`Bar` has 1 const parameter in the trait, but 0 in the impl",
+        ]
+    );
+}
+
+#[test]
+fn compatible_signatures() {
+    let trait_items: syn::ItemTrait = syn::parse_quote! {
+        trait Foo {
+            fn by_value(self);
+            fn boxed(self: Box<Self>);
+            fn with_lifetime<'a>(&'a self, x: &'a str);
+        }
+    };
+    let item_impl: syn::ItemImpl = syn::parse_quote! {
+        impl Foo for Bar {
+            fn by_value(mut self) {}
+            fn boxed(self: Box<Self>) {}
+            fn with_lifetime(&self, x: &str) {}
+        }
+    };
+    let remaining = subtract_items(&trait_items.items, &item_impl).unwrap();
+    assert!(remaining.fns.is_empty());
+}
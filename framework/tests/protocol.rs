@@ -0,0 +1,71 @@
+use portrait_framework::{impl_filler, FillImpl, NoArgs, PROTOCOL_VERSION};
+use proc_macro2::{Literal, TokenStream};
+use quote::quote;
+
+struct Filler;
+
+impl FillImpl for Filler {
+    type Args = NoArgs;
+
+    fn fill(
+        self,
+        portrait: &[syn::TraitItem],
+        _args: NoArgs,
+        _item_impl: &syn::ItemImpl,
+    ) -> syn::Result<TokenStream> {
+        let count = portrait.len();
+        Ok(quote!(#count))
+    }
+}
+
+fn invoke(portrait_tag: TokenStream, fill_tag: TokenStream) -> syn::Result<TokenStream> {
+    impl_filler(
+        quote! {
+            #portrait_tag
+            TRAIT_PORTRAIT { {fn foo();} }
+            #fill_tag
+            ARGS {}
+            IMPL { impl Foo for Bar {} }
+            DEBUG_PRINT_FILLER_OUTPUT { false }
+        },
+        Filler,
+    )
+}
+
+#[test]
+fn current_version() {
+    let version = Literal::u32_unsuffixed(PROTOCOL_VERSION);
+    let output =
+        invoke(quote!(PORTRAIT_PROTOCOL { #version }), quote!(FILL_PROTOCOL { #version })).unwrap();
+    assert_eq!(output.to_string(), "1usize");
+}
+
+#[test]
+fn untagged_legacy() {
+    let output = invoke(quote!(), quote!()).unwrap();
+    assert_eq!(output.to_string(), "1usize");
+}
+
+#[test]
+fn portrait_too_new() {
+    let version = Literal::u32_unsuffixed(PROTOCOL_VERSION + 1);
+    let err = invoke(quote!(PORTRAIT_PROTOCOL { #version }), quote!()).unwrap_err();
+    let message = err.to_string();
+    assert!(message.starts_with("the portrait of this trait uses portrait protocol version"));
+    assert!(message.contains("Upgrade the crate that provides the filler macro"));
+}
+
+#[test]
+fn fill_too_new() {
+    let version = Literal::u32_unsuffixed(PROTOCOL_VERSION + 1);
+    let err = invoke(quote!(), quote!(FILL_PROTOCOL { #version })).unwrap_err();
+    let message = err.to_string();
+    assert!(message.starts_with("the portrait attribute invoking this filler"));
+    assert!(message.contains("Upgrade the crate that provides the filler macro"));
+}
+
+#[test]
+fn forwarded_imports() {
+    let output = invoke(quote!(PORTRAIT_PROTOCOL { 1 }), quote!(IMPORTS { use foo::*; })).unwrap();
+    assert_eq!(output.to_string(), "use foo :: * ; 1usize");
+}
@@ -0,0 +1,21 @@
+use portrait_framework::{contains_self, is_self, is_self_or_ref};
+
+#[test]
+fn self_helpers() {
+    let cases: [(syn::Type, bool, bool, bool); 7] = [
+        (syn::parse_quote!(Self), true, true, true),
+        (syn::parse_quote!((Self)), true, true, true),
+        (syn::parse_quote!(&Self), false, true, true),
+        (syn::parse_quote!(&'a mut Self), false, true, true),
+        (syn::parse_quote!(&&Self), false, false, true),
+        (syn::parse_quote!(Option<Self>), false, false, true),
+        (syn::parse_quote!(Self::Item), false, false, false),
+    ];
+
+    for (ty, exact, or_ref, contains) in cases {
+        let name = quote::quote!(#ty).to_string();
+        assert_eq!(is_self(&ty), exact, "is_self({name})");
+        assert_eq!(is_self_or_ref(&ty), or_ref, "is_self_or_ref({name})");
+        assert_eq!(contains_self(&ty), contains, "contains_self({name})");
+    }
+}
@@ -0,0 +1,79 @@
+/// A default value that is available in constant contexts on stable Rust.
+///
+/// This is used by [`default`](crate::default) with the `const_default` option
+/// to fill associated constants,
+/// since [`Default::default()`] cannot be called in constants without nightly features.
+///
+/// Implement this trait for custom types to use them in filled constants:
+/// ```
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// impl portrait::ConstDefault for Point {
+///     const DEFAULT: Self = Point { x: 0, y: 0 };
+/// }
+/// ```
+pub trait ConstDefault: Sized {
+    /// The default value.
+    const DEFAULT: Self;
+}
+
+macro_rules! impl_zero {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl ConstDefault for $ty {
+                const DEFAULT: Self = 0 as $ty;
+            }
+        )*
+    };
+}
+
+impl_zero!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+impl ConstDefault for bool {
+    const DEFAULT: Self = false;
+}
+
+impl ConstDefault for char {
+    const DEFAULT: Self = '\0';
+}
+
+impl ConstDefault for &str {
+    const DEFAULT: Self = "";
+}
+
+impl<T> ConstDefault for &[T] {
+    const DEFAULT: Self = &[];
+}
+
+impl<T> ConstDefault for Option<T> {
+    const DEFAULT: Self = None;
+}
+
+impl<T: ConstDefault, const N: usize> ConstDefault for [T; N] {
+    const DEFAULT: Self = [T::DEFAULT; N];
+}
+
+macro_rules! impl_tuple {
+    ($($ty:ident),*) => {
+        impl<$($ty: ConstDefault),*> ConstDefault for ($($ty,)*) {
+            const DEFAULT: Self = ($($ty::DEFAULT,)*);
+        }
+    };
+}
+
+impl_tuple!();
+impl_tuple!(A);
+impl_tuple!(A, B);
+impl_tuple!(A, B, C);
+impl_tuple!(A, B, C, D);
+impl_tuple!(A, B, C, D, E);
+impl_tuple!(A, B, C, D, E, F);
+impl_tuple!(A, B, C, D, E, F, G);
+impl_tuple!(A, B, C, D, E, F, G, H);
+impl_tuple!(A, B, C, D, E, F, G, H, I);
+impl_tuple!(A, B, C, D, E, F, G, H, I, J);
+impl_tuple!(A, B, C, D, E, F, G, H, I, J, K);
+impl_tuple!(A, B, C, D, E, F, G, H, I, J, K, L);
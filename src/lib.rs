@@ -50,7 +50,9 @@
 //! trait FooBar {...}
 //! ```
 //!
-//! If the fill attribute fails with an error about undefined `foo_bar_portrait`,
+//! If all imports start with `crate`, `std`, `core` or `alloc`,
+//! importing the `FooBar` trait alone is sufficient for the fill attribute.
+//! Otherwise, if the fill attribute fails with an error about undefined `foo_bar_portrait`,
 //! import it manually together with the FooBar trait;
 //! the `#[portrait::make]` attribute generates this new module
 //! in the same module as the `FooBar` trait.
@@ -61,12 +63,23 @@
 //!
 //! - [`default`]:
 //!   Implements each missing method and constant by delegating to [`Default::default()`]
-//!   (`Default` is const-unstable and requires nightly with `#![feature(const_default_impls)]`).
+//!   (`Default` is const-unstable and requires nightly with `#![feature(const_default_impls)]`;
+//!   use the `const_default` option to fill constants with [`ConstDefault`] on stable)
+//!   or to values configured by name or by type.
 //! - [`delegate`]:
 //!   Proxies each missing method, constant and type
 //!   to an expression (usually `self.field`) or another type implementing the same trait.
 //! - [`log`]:
 //!   Calls a [`format!`]-like macro with the method arguments.
+//! - [`newtype`]:
+//!   Delegates to the wrapped type of a newtype, converting `Self` parameters and return values.
+//! - [`rules`]:
+//!   Invokes a `macro_rules!` filler with a simplified form of each missing item.
+//! - [`unreachable`]:
+//!   Implements methods of uninhabited types by matching on the receiver without any arms.
+//!
+//! The [`closures`] attribute is a standalone alternative to `#[portrait::fill]`
+//! that declares a struct of closures and implements the trait by calling them.
 //!
 //! ## How this works
 //!
@@ -136,6 +149,9 @@
 
 use core::fmt;
 
+mod const_default;
+pub use const_default::ConstDefault;
+
 /// Placeholder values when a [cfg](attr@cfg)-disabled parameter is used in [`log`].
 #[doc(hidden)]
 pub struct DummyDebug {
@@ -147,13 +163,160 @@ impl fmt::Debug for DummyDebug {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { f.write_str(self.text) }
 }
 
+/// **Impl filler**:
+/// Generates a struct with a closure for each associated function,
+/// and implements the trait for it by calling the closures.
+///
+/// This is useful for tests and plugins
+/// where the behavior of a trait implementation is built at runtime.
+///
+/// # Syntax
+/// ```
+/// # /*
+/// #[portrait::closures($vis:vis)]
+/// impl Trait for $name:ident {
+///     $($items:item)*
+/// }
+/// # */
+/// ```
+///
+/// Unlike other fillers, this is a dedicated attribute instead of an argument to [`fill`],
+/// because it declares the struct `$name` in addition to the impl block,
+/// so `$name` must not be declared elsewhere.
+/// The struct, its `new` function and its setters use the visibility `$vis` (private by default).
+/// Similar to [`fill`], the trait must be imported together with its portrait,
+/// and `@MOD_PATH($path)` can be prepended to `$vis` to specify the portrait module.
+///
+/// Each associated function with a `&self`, `&mut self` or `self` receiver
+/// becomes a boxed [`Fn`], [`FnMut`] or [`FnOnce`] field respectively,
+/// taking the non-receiver arguments and returning the same type.
+/// `$name::new()` (or [`Default::default()`]) creates a table
+/// where each function panics with [`unimplemented!`] until it is set
+/// with the generated `with_$fn` builder method.
+///
+/// Since the closures do not receive the receiver,
+/// lifetimes elided in the return type of a `&self` or `&mut self` function
+/// become `'static` in the closure,
+/// e.g. `fn name(&self) -> &str` is set with a closure returning `&'static str`.
+///
+/// Associated constants, associated types, functions without a receiver,
+/// `async fn`s and generic functions cannot be stored in closures
+/// and must be implemented manually as `$items`.
+///
+/// # Example
+/// ```
+/// #[portrait::make]
+/// trait Clock {
+///     fn now(&self) -> u64;
+///     fn advance(&mut self, by: u64);
+/// }
+///
+/// #[portrait::closures]
+/// impl Clock for ClockFns {}
+///
+/// let mut clock = ClockFns::new().with_now(|| 42);
+/// assert_eq!(clock.now(), 42);
+///
+/// let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| clock.advance(1)));
+/// assert!(result.is_err());
+/// ```
+#[doc(inline)]
+#[cfg(feature = "closures-filler")]
+pub use portrait_codegen::closures;
+#[doc(hidden)]
+#[cfg(feature = "closures-filler")]
+pub use portrait_codegen::closures_filler as __closures_filler;
+
+/// Names the closure table generated by [`closures`] in the struct it declares.
+#[doc(hidden)]
+pub trait ClosureTable {
+    /// The generated struct containing the closures.
+    type Table;
+}
+//
+
+//
 /// **Impl filler**:
 /// Generates a dummy implementation that returns [`Default::default()`]
 /// in all associated constants and functions.
 ///
+/// For `async fn`s and functions returning `impl Future`,
+/// the returned future resolves to [`Default::default()`].
+/// Other return-position `impl Trait`s are not supported.
+///
+/// # Syntax
+/// ```
+/// # /*
+/// #[portrait::fill(portrait::default($($arg),*))]
+/// # */
+/// ```
+///
+/// Each `$arg` is one of:
+/// - `$name:ident = $value:expr`:
+///   Uses `$value` for the associated constant or function called `$name`.
+/// - `for $ty:ty = $value:expr`:
+///   Uses `$value` for associated constants of type `$ty`
+///   and associated functions returning `$ty`,
+///   including `async fn`s returning `$ty` and functions returning `impl Future<Output = $ty>`.
+///   Types are compared by how they are written in the trait.
+/// - `types($($name:ident = $ty:ty),*)`:
+///   Implements the associated type called `$name` as `$ty`.
+///   Associated types cannot be implemented without this option.
+/// - `const_default`:
+///   Uses [`ConstDefault::DEFAULT`] instead of [`Default::default()`] for associated constants,
+///   which works on stable Rust.
+/// - `const_default($path:path)`:
+///   Like `const_default`, but uses `<T as $path>::DEFAULT`,
+///   e.g. when `portrait` is only reachable through a re-export
+///   or to use another trait with an associated `DEFAULT` constant.
+///
+/// Named values take precedence over type rules,
+/// and items matched by neither use [`Default::default()`]
+/// (or [`ConstDefault::DEFAULT`] for constants with `const_default`).
+///
+/// ```
+/// #[portrait::make]
+/// trait Config {
+///     const RETRIES: u32;
+///     type Output;
+///     fn name(&self) -> String;
+///     fn timeout(&self) -> u64;
+///     fn output(&self) -> Self::Output;
+/// }
+///
+/// struct Fallback;
+///
+/// #[portrait::fill(portrait::default(RETRIES = 3, for String = "n/a".into(), types(Output = Vec<u8>)))]
+/// impl Config for Fallback {}
+///
+/// assert_eq!(Fallback::RETRIES, 3);
+/// assert_eq!(Fallback.name(), "n/a");
+/// assert_eq!(Fallback.timeout(), 0);
+/// assert!(Fallback.output().is_empty());
+/// ```
+///
+/// ```
+/// #[portrait::make]
+/// trait Limits {
+///     const MAX: u32;
+///     const NAME: &'static str;
+///     const RANGE: (i64, i64);
+/// }
+///
+/// struct Unlimited;
+///
+/// #[portrait::fill(portrait::default(const_default, MAX = u32::MAX))]
+/// impl Limits for Unlimited {}
+///
+/// assert_eq!(Unlimited::MAX, u32::MAX);
+/// assert_eq!(Unlimited::NAME, "");
+/// assert_eq!(Unlimited::RANGE, (0, 0));
+/// ```
+///
 /// # Example
 /// ```
 /// // Constant defaults require the `const_default_impls` feature
+/// // unless `const_default` is used
 /// #![cfg_attr(feature = "const-default-impls", feature(const_default_impls))]
 ///
 /// #[portrait::make]
@@ -202,6 +365,51 @@ pub use portrait_codegen::default;
 /// - `$self_to_delegate_value` is an expression that returns the value to delegate methods with a receiver to.
 ///   References are automatically generated by the macro if required.
 ///
+/// `async fn`s are delegated by awaiting the future returned by the delegate.
+///
+/// Associated types specified in the impl block are kept as-is
+/// instead of being forwarded to the delegate.
+/// If the delegate has a different error type,
+/// append `; map_err = $map_err:expr` to convert the errors returned by the delegate:
+/// ```
+/// # /*
+/// #[portrait::fill(portrait::delegate($delegate_type:ty; $self_to_delegate_value:expr; map_err = $map_err:expr))]
+/// # */
+/// ```
+///
+/// `$map_err` is applied to the error of functions returning
+/// `Result<T, Self::Error>`, `Poll<Result<T, Self::Error>>` or `Option<Result<T, Self::Error>>`,
+/// where `Error` is any associated type specified in the impl block
+/// (spelled `Self::Error` or `<Self as Trait>::Error`).
+/// The return types are matched syntactically:
+/// concrete error types, associated types forwarded to the delegate,
+/// type aliases like `io::Result<T>` and other wrappers are returned unchanged.
+/// Setting `map_err` is an error if it does not apply to any generated function.
+///
+/// ```
+/// #[portrait::make]
+/// trait Parse {
+///     type Error;
+///     fn parse(&self, input: &str) -> Result<u32, Self::Error>;
+/// }
+///
+/// struct Decimal;
+/// impl Parse for Decimal {
+///     type Error = std::num::ParseIntError;
+///     fn parse(&self, input: &str) -> Result<u32, Self::Error> { input.parse() }
+/// }
+///
+/// struct Lenient(Decimal);
+///
+/// #[portrait::fill(portrait::delegate(Decimal; self.0; map_err = |err| err.to_string()))]
+/// impl Parse for Lenient {
+///     type Error = String;
+/// }
+///
+/// assert_eq!(Lenient(Decimal).parse("12"), Ok(12));
+/// assert!(Lenient(Decimal).parse("x").is_err());
+/// ```
+///
 /// # Example
 /// ```
 /// #[portrait::make]
@@ -263,6 +471,24 @@ pub use portrait_codegen::delegate;
 /// The `(...)` parts are optional arguments passed for the option or the filler.
 /// If the option/filler does not expect any arguments, the entire parentheses may be omitted.
 ///
+/// Multiple traits can be derived in the same attribute, separated by commas.
+/// A trait without its own `with` clause is derived with the filler of the next `with` clause:
+///
+/// ```
+/// # /*
+/// // derives `Foo`, `Bar` and `Baz` with `derive_delegate`
+/// #[portrait::derive(Foo, Bar, Baz with portrait::derive_delegate)]
+/// struct Foo { /* ... */ }
+///
+/// // derives `Foo` with `filler_a` and `Bar` with `filler_b(arg)`
+/// #[portrait::derive(Foo with filler_a, Bar with filler_b(arg))]
+/// struct Bar { /* ... */ }
+/// # */
+/// ```
+///
+/// Each trait is derived in a separate impl,
+/// so errors from one trait do not affect the others.
+///
 /// ## Special options
 ///
 /// ### `DEBUG_PRINT_FILLER_OUTPUT`
@@ -275,6 +501,7 @@ pub use portrait_codegen::delegate;
 ///
 /// Specifies the derived module path if it is imported differently
 /// or overridden with `name` in [`#[make]`](make).
+/// This option can only be used when a single trait is derived.
 #[doc(inline)]
 pub use portrait_codegen::derive;
 //
@@ -294,6 +521,8 @@ pub use portrait_codegen::derive;
 /// If the parameter type is `Self`/`&Self`/`&mut Self`,
 /// the corresponding field is passed to the delegation call instead.
 ///
+/// For `async fn`s, each delegation call is awaited before the next field is called.
+///
 /// ## Return values
 /// If the return type is `()`, no return values are involved.
 ///
@@ -354,6 +583,110 @@ pub use portrait_codegen::derive;
 /// # */
 /// ```
 ///
+/// ## `first_some`, `first_ok`, `first_err`, `all`, `any`, `until`
+/// These options combine the return values like `reduce`,
+/// but stop delegating to later fields once the result is known.
+/// Each delegation result except the last one is checked with a predicate;
+/// if the predicate holds, the result is returned without calling the remaining fields.
+/// Otherwise, the result of the last field is returned.
+///
+/// | Option | Return type | Stops at |
+/// | :---: | :---: | :---: |
+/// | `first_some` | `Option<T>` | the first `Some` |
+/// | `first_ok` | `Result<T, E>` | the first `Ok` |
+/// | `first_err` | `Result<T, E>` | the first `Err` |
+/// | `all` | `bool` | the first `false` |
+/// | `any` | `bool` | the first `true` |
+/// | `until = pred` | `T` | the first value where `pred(&value)` returns `true` |
+///
+/// These options cannot be used together with `reduce`.
+///
+/// ```
+/// # /*
+/// /// Looks up the key in each cache layer until it is found.
+/// #[portrait(derive_delegate(first_some))]
+/// fn get(&self, key: &str) -> Option<Value>;
+///
+/// /// Stops at the first field whose priority exceeds 10.
+/// #[portrait(derive_delegate(until = |&priority| priority > 10))]
+/// fn priority(&self) -> u32;
+/// # */
+/// ```
+///
+/// ## `collect`, `chain`
+/// If the `collect` option is applied,
+/// the return value of each delegation call must implement [`IntoIterator`],
+/// and the items of all return values are collected into the return type
+/// through [`FromIterator`], e.g. into a `Vec` or a `HashSet`.
+/// The results are flattened rather than gathered per field:
+/// each field returns the same type as the trait function,
+/// so `fn keys(&self) -> Vec<Key>` returns the keys of all fields in one `Vec`,
+/// not a tuple, array or `Vec` with one element per field.
+/// Return types that do not implement both [`IntoIterator`] and [`FromIterator`]
+/// are rejected by the compiler:
+///
+/// ```compile_fail
+/// #[portrait::make]
+/// trait Counter {
+///     #[portrait(derive_delegate(collect))]
+///     fn count(&self) -> u32;
+/// }
+///
+/// impl Counter for u32 {
+///     fn count(&self) -> u32 { *self }
+/// }
+///
+/// // error: `u32` is not an iterator
+/// #[portrait::derive(Counter with portrait::derive_delegate)]
+/// struct Counters(u32, u32);
+/// ```
+///
+/// If the `chain` option is applied,
+/// the iterators returned by the delegation calls are chained with [`Iterator::chain`].
+/// This is useful for return-position `impl Iterator` types,
+/// where each field returns a different iterator type
+/// and `enum_either` is not applicable for structs.
+/// Structs without fields return [`std::iter::empty`].
+///
+/// These options cannot be used together with `reduce` or the short-circuiting strategies.
+///
+/// ```
+/// # /*
+/// /// Returns the keys from all fields.
+/// #[portrait(derive_delegate(collect))]
+/// fn keys(&self) -> Vec<Key>;
+///
+/// /// Iterates over the values of all fields.
+/// #[portrait(derive_delegate(chain))]
+/// fn values(&self) -> impl Iterator<Item = &Value>;
+/// # */
+/// ```
+///
+/// ## `order`
+/// Fields are delegated to in declaration order by default.
+/// `order = reverse` delegates in reverse declaration order,
+/// which is useful for teardown-style functions.
+///
+/// `order = priority` delegates to fields in descending order of their priority,
+/// which is specified with `#[portrait(derive_delegate(priority = N))]` on the field.
+/// Fields without a priority have priority 0,
+/// and fields with equal priority are delegated to in declaration order.
+/// Functions without `order = priority` ignore the field priorities,
+/// but a `priority` is rejected if no function of the trait uses `order = priority`.
+///
+/// The order applies to struct fields and the fields of each enum variant alike,
+/// and also determines the order in which return values are combined.
+///
+/// ```
+/// # /*
+/// #[portrait(derive_delegate(order = reverse))]
+/// fn shutdown(&mut self);
+///
+/// #[portrait(derive_delegate(order = priority))]
+/// fn on_event(&mut self, event: &Event);
+/// # */
+/// ```
+///
 /// ## `enum_either`
 /// If the `enum_either` option is applied, when deriving from enums,
 /// each match arm is wrapped with a nested tree of `Either::Left(..)`/`Either::Right(..)`s
@@ -428,6 +761,46 @@ pub use portrait_codegen::derive;
 /// }
 /// ```
 ///
+/// Unions are supported if the union specifies how to identify the initialized field.
+/// The union must have the attribute
+/// `#[portrait(derive_delegate(union_tag = expr, unsafe_union))]`,
+/// and each field must have the attribute `#[portrait(derive_delegate(tag = pat))]`.
+/// `expr` is matched against the `pat` of each field,
+/// and the matching field is accessed in an `unsafe` block for delegation.
+/// The `unsafe_union` option acknowledges that `expr` always identifies the initialized field;
+/// an unmatched tag panics.
+/// `tag` is rejected on struct and enum fields,
+/// and `priority` is rejected on union fields.
+///
+/// Unions have the same restrictions as enums,
+/// and typed receivers like `self: Box<Self>` are not supported.
+/// ```
+/// use std::mem::ManuallyDrop;
+///
+/// #[portrait::make]
+/// trait Foo {
+///     fn print(&self);
+/// }
+///
+/// # #[portrait::fill(portrait::default)]
+/// impl Foo for ManuallyDrop<i32> {}
+/// # #[portrait::fill(portrait::default)]
+/// impl Foo for ManuallyDrop<String> {}
+///
+/// #[portrait::derive(Foo with portrait::derive_delegate)]
+/// #[portrait(derive_delegate(union_tag = self.tag(), unsafe_union))]
+/// union Raw {
+///     #[portrait(derive_delegate(tag = 0))]
+///     int:    ManuallyDrop<i32>,
+///     #[portrait(derive_delegate(tag = 1))]
+///     string: ManuallyDrop<String>,
+/// }
+///
+/// impl Raw {
+///     fn tag(&self) -> u8 { unimplemented!() }
+/// }
+/// ```
+///
 /// Traits are implemented for generic types as long as the implementation is feasible,
 /// unlike the standard macros that implement on the generic variables directly.
 /// ```
@@ -453,6 +826,59 @@ pub use portrait_codegen::derive;
 pub use portrait_codegen::derive_delegate;
 //
 
+//
+/// Extracts a trait from the public associated functions of an inherent impl block.
+///
+/// This is useful for mocking a concrete type through a trait.
+///
+/// # Usage
+/// ```
+/// # /*
+/// #[portrait::extract($vis trait TraitName, $make_args)]
+/// impl Type {
+///     pub fn method(&self) { /* ... */ }
+/// }
+/// # */
+/// ```
+///
+/// A trait called `TraitName` is declared with the signatures of
+/// all associated functions with an explicit visibility in the impl block.
+/// Argument patterns are replaced with plain identifiers in the trait.
+/// The trait is passed to [`#[make]`](make) with the optional `$make_args`,
+/// so the trait can be used with [`fill`] and [`derive`] like any other portrait trait.
+///
+/// The trait is implemented for `Type` by calling the inherent associated functions.
+///
+/// The trait itself is not generic,
+/// so the extracted signatures cannot use the generic parameters of the impl block.
+///
+/// # Example
+/// ```
+/// struct Client {
+///     base_url: String,
+/// }
+///
+/// #[portrait::extract(pub trait ClientApi)]
+/// impl Client {
+///     pub fn new(base_url: String) -> Self { Self { base_url } }
+///
+///     pub fn url(&self, path: &str) -> String { format!("{}/{path}", self.base_url) }
+///
+///     fn private_helper(&self) {}
+/// }
+///
+/// #[portrait::fill(portrait::default)]
+/// impl ClientApi for () {}
+///
+/// fn fetch(client: &impl ClientApi) -> String { client.url("users") }
+///
+/// assert_eq!(fetch(&Client::new("https://example.com".into())), "https://example.com/users");
+/// assert_eq!(fetch(&()), "");
+/// ```
+#[doc(inline)]
+pub use portrait_codegen::extract;
+//
+
 //
 /// Invokes a portrait macro on the applied impl block.
 ///
@@ -494,6 +920,89 @@ pub use portrait_codegen::derive_delegate;
 pub use portrait_codegen::fill;
 //
 
+//
+/// Fills an inherent impl block with the associated functions and constants of a trait,
+/// forwarding to the implementation of the trait.
+///
+/// This allows callers to use the trait API without importing the trait.
+///
+/// # Usage
+/// ```
+/// # /*
+/// #[portrait::fill_inherent(@OPTION1(...) @OPTION2 $vis path::to::Trait)]
+/// impl Type {
+///     // items defined here are not forwarded
+/// }
+/// # */
+/// ```
+///
+/// Each associated function is generated with the same signature as the trait function,
+/// calling `<Self as Trait>::function(...)` with the same arguments
+/// (and awaiting the result for `async fn`s).
+/// Associated constants are forwarded to `<Self as Trait>::CONST`.
+/// Associated types are not forwarded since inherent associated types are unstable;
+/// `Self::Assoc` in signatures is rewritten to `<Self as Trait>::Assoc`.
+///
+/// `$vis` is the visibility of the generated items, e.g. `pub` or `pub(crate)`.
+/// The generated items are private if it is omitted.
+///
+/// Items already defined in the impl block are not generated.
+/// Note that inherent methods take precedence over trait methods in method call syntax,
+/// so such items also shadow the trait methods in `self.method()` calls
+/// within the trait impl.
+///
+/// ## Special options
+///
+/// ### `EXCLUDE`
+/// > Syntax: `@EXCLUDE(item1, item2, ...)`
+///
+/// Does not generate inherent items for the specified trait items.
+///
+/// ### `DEBUG_PRINT_FILLER_OUTPUT`
+/// > Syntax: `@DEBUG_PRINT_FILLER_OUTPUT`
+///
+/// Prints the generated impl block.
+///
+/// ### `MOD_PATH`
+/// > Syntax: `@MOD_PATH(path::to::name)`
+///
+/// Specifies the derived module path if it is imported differently
+/// or overridden with `name` in [`#[make]`](make).
+///
+/// # Example
+/// ```
+/// mod api {
+///     #[portrait::make]
+///     pub trait Counter {
+///         const START: u32;
+///         fn get(&self) -> u32;
+///         fn add(&mut self, delta: u32);
+///         fn reset(&mut self) { self.add(0) }
+///     }
+/// }
+///
+/// struct Count(u32);
+///
+/// impl api::Counter for Count {
+///     const START: u32 = 0;
+///     fn get(&self) -> u32 { self.0 }
+///     fn add(&mut self, delta: u32) { self.0 += delta }
+/// }
+///
+/// #[portrait::fill_inherent(@EXCLUDE(reset) pub api::Counter)]
+/// impl Count {}
+///
+/// // no need to import `api::Counter`
+/// let mut count = Count(Count::START);
+/// count.add(2);
+/// assert_eq!(count.get(), 2);
+/// ```
+#[doc(inline)]
+pub use portrait_codegen::fill_inherent;
+#[doc(hidden)]
+pub use portrait_codegen::fill_inherent_filler as __fill_inherent_filler;
+//
+
 //
 /// **Impl filler**:
 /// Generates an implementation that simply logs the parameters and returns `()`.
@@ -522,6 +1031,9 @@ pub use portrait_codegen::fill;
 ///
 /// Currently, this macro does not properly support `#[cfg]` on arguments yet.
 ///
+/// For `async fn`s and functions returning `impl Future`,
+/// the message is logged when the returned future is polled.
+///
 /// # Example
 /// ```
 /// // Imports required for calling the `write!` macro
@@ -565,17 +1077,316 @@ pub use portrait_codegen::log;
 /// Sets the derived module name to `$name`.
 /// Users for the [`#[fill]`] part have to import this name.
 ///
+/// The name of the exported portrait macro is derived from
+/// the crate name, the derived module name and the tokens of the trait.
+/// If a crate declares two identical `pub` traits with the same derived module name
+/// (e.g. the same trait in different modules),
+/// the compiler rejects the duplicate macro definitions,
+/// and one of them needs a different `name`.
+///
 /// ## `import`
 /// > Syntax: `import($($imports:UseTree)*)`
 ///
 /// Import the [use trees](https://docs.rs/syn/1/syn/enum.UseTree.html) in `$imports`
 /// in the scope of the `impl` block.
 ///
+/// Use trees starting with `crate`, `std`, `core` or `alloc` are absolute
+/// and are imported by the derived macro directly.
+/// If the trait only has absolute imports (or none at all),
+/// implementors only need to import the trait itself.
+/// Otherwise, the use trees are resolved relative to the derived module
+/// (e.g. `super::super::Foo` for `Foo` in the trait module),
+/// and implementors also need to import the derived module
+/// unless the trait module is passed with [`module`](#module).
+///
 /// ## `auto_imports`
 /// > Syntax: `auto_imports`
 ///
-/// An experimental feature for detecting imports automatically.
-/// Requires the imports to be `pub use` (or `pub(crate) use` if the trait is also `pub(crate)`)
-/// in order to re-export from the derived module.
+/// Detects the imports required by the signatures of the trait items automatically,
+/// so that `import(...)` does not need to repeat the `use` declarations of the trait module.
+///
+/// The first segment of each path in the item signatures is imported from the trait module,
+/// except for:
+///
+/// - `Self` and the generic parameters of the trait and its items,
+/// - primitive types and names in the standard prelude (e.g. `Option`, `Vec`, `Clone`),
+/// - absolute paths starting with `::`, `crate::`, `std::`, `core::` or `alloc::`.
+///
+/// Paths starting with `self::` or `super::` are resolved relative to the trait module,
+/// e.g. `super::Foo` imports `Foo` from the parent module of the trait.
+/// Function bodies, default values and attributes are not scanned.
+///
+/// The imported names must be visible enough to be re-exported from the derived module,
+/// e.g. `pub use` (or `pub(crate) use` if the trait is also `pub(crate)`) for imported items.
+/// Two different items with the same name cannot be imported;
+/// use `import(...)` for such cases.
+/// Since the detected imports are relative to the trait module,
+/// implementors also need to import the derived module if any import is detected,
+/// unless the trait module is passed with [`module`](#module).
+///
+/// ## `module`
+/// > Syntax: `module = $path:path`
+///
+/// Declares the absolute path of the module containing the trait,
+/// e.g. `module = crate::foo::bar` or `module = crate` for the crate root.
+/// The path must start with `crate`.
+///
+/// The derived macro then imports the relative imports from `$path::trait_portrait::imports`,
+/// which also works from other crates,
+/// so implementors only need to import the trait itself.
+/// A wrong path fails to compile at the trait.
+///
+/// ```
+/// mod shapes {
+///     pub use std::collections::BTreeMap;
+///
+///     #[portrait::make(module = crate::shapes, auto_imports)]
+///     pub trait Registry {
+///         fn shapes(&self) -> BTreeMap<u32, String>;
+///     }
+/// }
+///
+/// use shapes::Registry;
+///
+/// struct Empty;
+///
+/// #[portrait::fill(portrait::default)]
+/// impl Registry for Empty {}
+///
+/// # fn main() {
+/// assert!(Empty.shapes().is_empty());
+/// # }
+/// ```
+///
+/// ## `export`
+/// > Syntax: `export = $strategy:(macro_export|facade)`
+///
+/// Determines how the derived macro of a `pub` trait is re-exported.
+/// With either strategy, the macro itself is exported at the crate root
+/// under a hidden hashed name with `#[macro_export]`,
+/// since `macro_rules!` macros cannot be used outside their crate otherwise.
+///
+/// - `macro_export` (default):
+///   The macro is re-exported next to the trait under the name of the trait.
+/// - `facade`:
+///   The macro is also re-exported through a hidden `__portraits` module in the derived module,
+///   e.g. `trait_portrait::__portraits::Trait`,
+///   and the hidden re-export next to the trait goes through that module.
+///
+/// Other crates can implement the trait with [`fill`] and [`derive`] with either strategy.
+/// Traits with restricted visibility are never exported at the crate root.
+///
+/// # Re-exporting portraits
+/// The derived macro is re-exported with the same name as the trait,
+/// so `pub use dependency::Trait;` re-exports both the trait and its portrait
+/// from a facade crate.
+/// If the trait has relative imports, also re-export the derived module,
+/// e.g. `pub use dependency::trait_portrait;`.
+///
+/// # Paths in trait items
+/// `crate::` paths in the trait items are passed to fillers as `$crate::`,
+/// so they refer to the crate declaring the trait even if the trait is implemented in another crate.
+/// Other paths are resolved in the scope of the impl block,
+/// with the imports described above.
+///
+/// # Declarative fillers
+/// The declarative macro generated for the trait (with the same name as the trait)
+/// can also expand each trait item into an invocation of a `macro_rules!` filler
+/// in the [simplified form](rules#simplified-form):
+///
+/// ```
+/// # /*
+/// impl Trait for Type {
+///     Trait! { @RULES {my_filler} @ARGS {...} }
+/// }
+/// # */
+/// ```
+///
+/// Use the [`rules`] filler instead to only generate the items missing from an impl block.
 #[doc(inline)]
 pub use portrait_codegen::make;
+//
+
+//
+/// **Impl filler**:
+/// Generates an implementation for a newtype
+/// by delegating to the implementation of the wrapped type
+/// and converting `Self` values from and to the wrapped type.
+///
+/// # Syntax
+/// ```
+/// # /*
+/// #[portrait::fill(portrait::newtype($inner_type:ty; $accessor:expr $(; $ctor:expr)?))]
+/// # */
+/// ```
+///
+/// - `$inner_type` is the wrapped type that also implements the trait.
+/// - `$accessor` is an expression that gets the inner value from `self`, e.g. `self.0`.
+///   It is applied to all `Self` values passed to the function, not just the receiver.
+/// - `$ctor` is a function that wraps an inner value into `Self`.
+///   Defaults to `Self`, i.e. the constructor of a tuple struct.
+///
+/// Unlike [`delegate`], parameters and return values that mention `Self` are converted:
+///
+/// | Type | Parameter | Return value |
+/// | :--: | :-------: | :----------: |
+/// | `Self` | `$accessor` | `$ctor(value)` |
+/// | `&Self`, `&mut Self` | `&$accessor`, `&mut $accessor` | unsupported |
+/// | `Option<_>`, `Result<_, E>` | converted with `.map()` | converted with `.map()` |
+/// | `Vec<_>` | converted element-wise | converted element-wise |
+///
+/// Constants of type `Self` are wrapped with `$ctor`.
+/// Other types mentioning `Self` are rejected, so the item must be implemented manually.
+///
+/// # Example
+/// ```
+/// #[portrait::make]
+/// trait Merge {
+///     fn merge(self, other: Self) -> Self;
+///     fn merge_all(items: Vec<Self>) -> Option<Self>
+///     where
+///         Self: Sized;
+///     fn first<'a>(&'a self, other: &'a Self) -> &'a u32;
+/// }
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Max(u32);
+///
+/// impl Merge for Max {
+///     fn merge(self, other: Self) -> Self { Max(self.0.max(other.0)) }
+///     fn merge_all(items: Vec<Self>) -> Option<Self> { items.into_iter().reduce(Self::merge) }
+///     fn first<'a>(&'a self, _other: &'a Self) -> &'a u32 { &self.0 }
+/// }
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Wrapper(Max);
+///
+/// #[portrait::fill(portrait::newtype(Max; self.0))]
+/// impl Merge for Wrapper {}
+///
+/// assert_eq!(Wrapper(Max(1)).merge(Wrapper(Max(3))), Wrapper(Max(3)));
+/// assert_eq!(
+///     Wrapper::merge_all(vec![Wrapper(Max(2)), Wrapper(Max(5))]),
+///     Some(Wrapper(Max(5))),
+/// );
+/// assert_eq!(*Wrapper(Max(4)).first(&Wrapper(Max(1))), 4);
+/// ```
+#[doc(inline)]
+#[cfg(feature = "newtype-filler")]
+pub use portrait_codegen::newtype;
+//
+
+//
+/// **Impl filler**:
+/// Generates the missing items by invoking a `macro_rules!` filler for each item.
+///
+/// # Syntax
+/// ```
+/// # /*
+/// #[portrait::fill(portrait::rules($filler:path $(, $args:tt*)?))]
+/// # */
+/// ```
+///
+/// `$filler` is invoked once for each unimplemented trait item,
+/// in the declaration order of the trait,
+/// with the `$args` and the item in the [simplified form](#simplified-form).
+/// The invocations are placed in the impl block,
+/// so `$filler` should expand to a single impl item.
+///
+/// # Simplified form
+/// Each trait item is pre-classified into one of the `@fn`, `@const` and `@type` forms
+/// specified in [`portrait_framework::rules`](https://docs.rs/portrait-framework/0.3/portrait_framework/rules/),
+/// which are part of the stable public interface of portrait.
+///
+/// The filler only needs to accept the forms of the kinds of items present in the trait.
+///
+/// # Example
+/// ```
+/// #[portrait::make]
+/// trait Shape {
+///     const SIDES: usize;
+///     type Unit;
+///     fn area(&self) -> f64;
+///     fn describe(&self, name: &str) -> String;
+/// }
+///
+/// macro_rules! unsupported {
+///     (@ARGS {$message:literal} @fn $(#[$attr:meta])* {$($sig:tt)*} $name:ident ($($self_:ident)?) ($($arg:ident: $arg_ty:ty),*) -> $ret:ty;) => {
+///         $($sig)* {
+///             $(let _ = $arg;)*
+///             panic!(concat!(stringify!($name), ": ", $message))
+///         }
+///     };
+///     (@ARGS {$message:literal} @const $(#[$attr:meta])* $name:ident: $ty:ty;) => {
+///         const $name: $ty = panic!($message);
+///     };
+///     (@ARGS {$message:literal} @type $(#[$attr:meta])* $name:ident [$($generics:tt)*] where [$($where:tt)*];) => {
+///         type $name<$($generics)*> = () where $($where)*;
+///     };
+/// }
+///
+/// struct Square(f64);
+///
+/// #[portrait::fill(portrait::rules(unsupported, "not supported"))]
+/// impl Shape for Square {
+///     const SIDES: usize = 4;
+///     fn area(&self) -> f64 { self.0 * self.0 }
+/// }
+///
+/// assert_eq!(Square(2.0).area(), 4.0);
+/// let _: <Square as Shape>::Unit = ();
+/// ```
+#[doc(inline)]
+#[cfg(feature = "rules-filler")]
+pub use portrait_codegen::rules;
+//
+
+//
+/// **Impl filler**:
+/// Implements a trait for an uninhabited type, e.g. `enum Never {}`,
+/// by matching on the receiver without any arms.
+///
+/// # Syntax
+/// ```
+/// # /*
+/// #[portrait::fill(portrait::unreachable($($arg),*))]
+/// # */
+/// ```
+///
+/// Each `$arg` is one of:
+/// - `$name:ident = $value:expr`:
+///   Uses `$value` for the associated constant called `$name`,
+///   or as the body of the associated function called `$name`.
+/// - `types($($name:ident = $ty:ty),*)`:
+///   Implements the associated type called `$name` as `$ty`.
+///
+/// Associated functions with a receiver
+/// (`self`, `&self`, `&mut self`, `self: Box<Self>`, etc.)
+/// are implemented as `match *self {}` (or `match self {}` for `self`),
+/// which compiles only if the type is actually uninhabited.
+/// Associated constants and associated functions without a receiver
+/// cannot be implemented this way and require an explicit `$value`.
+/// Associated types are implemented as `Self` unless specified in `types(...)`.
+///
+/// # Example
+/// ```
+/// #[portrait::make]
+/// trait Backend {
+///     const NAME: &'static str;
+///     type Error;
+///     fn new() -> Self;
+///     fn connect(&mut self, address: &str) -> Result<(), Self::Error>;
+///     fn close(self) -> u32;
+/// }
+///
+/// /// A backend that can never be used.
+/// enum Never {}
+///
+/// #[portrait::fill(portrait::unreachable(NAME = "never", new = panic!("no backend"), types(Error = String)))]
+/// impl Backend for Never {}
+///
+/// assert_eq!(Never::NAME, "never");
+/// ```
+#[doc(inline)]
+#[cfg(feature = "unreachable-filler")]
+pub use portrait_codegen::unreachable;
@@ -0,0 +1,120 @@
+#![allow(clippy::manual_async_fn)]
+
+use std::future::Future;
+use std::pin::pin;
+use std::task::{Context, Poll, Waker};
+
+#[portrait::make]
+trait Store {
+    async fn get(&self, key: u32) -> Option<u32>;
+    async fn set(&mut self, key: u32, value: u32);
+    fn count(&self) -> impl Future<Output = usize>;
+    fn touch(&mut self, key: u32) -> impl Future<Output = ()>;
+}
+
+#[derive(Default)]
+struct Empty;
+
+#[portrait::fill(portrait::default)]
+impl Store for Empty {}
+
+struct Single {
+    key:   u32,
+    value: u32,
+}
+
+impl Store for Single {
+    async fn get(&self, key: u32) -> Option<u32> { (key == self.key).then_some(self.value) }
+    async fn set(&mut self, key: u32, value: u32) {
+        self.key = key;
+        self.value = value;
+    }
+    fn count(&self) -> impl Future<Output = usize> { async { 1 } }
+    fn touch(&mut self, key: u32) -> impl Future<Output = ()> { async move { self.key = key } }
+}
+
+struct Wrapper {
+    inner: Single,
+}
+
+#[portrait::fill(portrait::delegate(Single; self.inner))]
+impl Store for Wrapper {}
+
+macro_rules! record {
+    ($buffer:expr, $($args:tt)*) => {
+        $buffer.push_str(&format!($($args)*))
+    };
+}
+
+#[derive(Default)]
+struct Logger {
+    buffer: String,
+}
+
+#[portrait::fill(portrait::log(record, self.buffer))]
+impl Store for Logger {
+    async fn get(&self, _key: u32) -> Option<u32> { None }
+    fn count(&self) -> impl Future<Output = usize> { async { 0 } }
+}
+
+#[portrait::make]
+trait Flush {
+    #[portrait(derive_delegate(reduce = |a, b| a + b))]
+    async fn flush(&mut self) -> usize;
+}
+
+#[portrait::fill(portrait::default)]
+impl Flush for Empty {}
+
+impl Flush for Single {
+    async fn flush(&mut self) -> usize { self.value as usize }
+}
+
+#[portrait::derive(Flush with portrait::derive_delegate)]
+struct Both {
+    empty:  Empty,
+    single: Single,
+}
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let mut context = Context::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+    }
+}
+
+#[test]
+fn default_async() {
+    assert_eq!(block_on(Empty.get(1)), None);
+    assert_eq!(block_on(Empty.count()), 0);
+    block_on(Empty.touch(1));
+}
+
+#[test]
+fn delegate_async() {
+    let mut wrapper = Wrapper { inner: Single { key: 0, value: 0 } };
+    block_on(wrapper.set(1, 2));
+    assert_eq!(block_on(wrapper.get(1)), Some(2));
+    assert_eq!(block_on(wrapper.count()), 1);
+    block_on(wrapper.touch(3));
+    assert_eq!(wrapper.inner.key, 3);
+}
+
+#[test]
+fn log_async() {
+    let mut logger = Logger::default();
+    block_on(logger.set(1, 2));
+    assert_eq!(logger.buffer, "set(1, 2)");
+
+    block_on(logger.touch(3));
+    assert_eq!(logger.buffer, "set(1, 2)touch(3)");
+}
+
+#[test]
+fn derive_delegate_async() {
+    let mut both = Both { empty: Empty, single: Single { key: 0, value: 3 } };
+    assert_eq!(block_on(both.flush()), 3);
+}
@@ -0,0 +1,101 @@
+#![allow(dead_code)]
+
+mod types {
+    #[derive(Debug, Default, PartialEq)]
+    pub struct Key;
+
+    #[derive(Debug, Default, PartialEq)]
+    pub struct Value;
+
+    pub mod nested {
+        #[derive(Debug, Default, PartialEq)]
+        pub struct Extra;
+    }
+}
+
+mod outer {
+    #[derive(Debug, Default, PartialEq)]
+    pub struct OuterType;
+
+    pub mod api {
+        pub use crate::types::{nested, Key};
+
+        #[derive(Debug, Default, PartialEq)]
+        pub struct LocalType;
+
+        #[portrait::make(auto_imports)]
+        pub trait Store {
+            type Out: Default;
+
+            const NAME: Option<&'static str>;
+
+            fn get<T>(&self, key: Key) -> Option<T>;
+            fn extra(&self) -> nested::Extra;
+            fn outer(&self) -> super::OuterType;
+            fn local(&self) -> self::LocalType;
+            fn generic<U: Default>(&self, u: U) -> Vec<U>;
+            fn absolute(&self) -> crate::types::Value;
+            fn std_path(&self) -> std::string::String;
+            fn output(&self) -> Self::Out;
+        }
+    }
+}
+
+mod impls {
+    use crate::outer::api::{store_portrait, Store};
+
+    pub struct Impl;
+
+    #[portrait::fill(portrait::default)]
+    impl Store for Impl {
+        type Out = u8;
+
+        const NAME: Option<&'static str> = None;
+    }
+}
+
+#[test]
+fn test_auto_imports_across_modules() {
+    use outer::api::Store;
+
+    let value = impls::Impl;
+    assert_eq!(value.get::<u32>(types::Key), None);
+    assert_eq!(value.extra(), types::nested::Extra);
+    assert_eq!(value.outer(), outer::OuterType);
+    assert_eq!(value.local(), outer::api::LocalType);
+    assert_eq!(value.generic(1u64), Vec::<u64>::new());
+    assert_eq!(value.absolute(), types::Value);
+    assert_eq!(value.std_path(), String::new());
+    assert_eq!(value.output(), 0);
+    assert_eq!(<impls::Impl as Store>::NAME, None);
+}
+
+mod private {
+    #[derive(Debug, Default, PartialEq)]
+    pub struct Top;
+
+    pub mod inner {
+        pub mod api {
+            #[portrait::make(auto_imports)]
+            pub(crate) trait Private {
+                fn top(&self) -> super::super::Top;
+            }
+
+            pub(crate) mod impls {
+                use super::{private_portrait, Private};
+
+                pub struct Impl;
+
+                #[portrait::fill(portrait::default)]
+                impl Private for Impl {}
+            }
+        }
+    }
+}
+
+#[test]
+fn test_auto_imports_super_super() {
+    use private::inner::api::Private;
+
+    assert_eq!(private::inner::api::impls::Impl.top(), private::Top);
+}
@@ -0,0 +1,125 @@
+use std::cell::Cell;
+use std::rc::Rc;
+
+#[portrait::make]
+trait Store {
+    type Key;
+
+    fn get(&self, key: &Self::Key) -> Option<u32>;
+    fn set(&mut self, key: Self::Key, value: u32);
+    fn name<'a>(&self, prefix: &'a str) -> &'a str;
+    fn finish(self) -> usize;
+    fn capacity() -> usize;
+}
+
+#[portrait::closures(pub(crate))]
+impl Store for StoreFns {
+    type Key = String;
+
+    fn capacity() -> usize { 16 }
+}
+
+#[test]
+fn calls_closures() {
+    let writes = Rc::new(Cell::new(0));
+    let writes_in_set = writes.clone();
+
+    let mut store = StoreFns::new()
+        .with_get(|key| (key == "answer").then_some(42))
+        .with_set(move |_, value| writes_in_set.set(writes_in_set.get() + value as usize))
+        .with_name(|prefix| prefix)
+        .with_finish(|| 7);
+
+    assert_eq!(store.get(&"answer".to_string()), Some(42));
+    assert_eq!(store.get(&"other".to_string()), None);
+    store.set("a".into(), 3);
+    store.set("b".into(), 4);
+    assert_eq!(writes.get(), 7);
+    assert_eq!(store.name("prefix"), "prefix");
+    assert_eq!(StoreFns::capacity(), 16);
+    assert_eq!(store.finish(), 7);
+}
+
+#[test]
+#[should_panic = "`get` is not set"]
+fn unset_panics() {
+    let store = StoreFns::default();
+    store.get(&String::new());
+}
+
+mod shapes {
+    use std::fmt::Display;
+
+    #[portrait::make(import(std::fmt::Display))]
+    pub trait Shape {
+        fn describe(&self) -> Box<dyn Display>;
+        fn same_as(&self, other: &Self) -> bool;
+    }
+}
+
+use shapes::Shape;
+
+#[portrait::closures]
+impl Shape for ShapeFns {}
+
+#[test]
+fn imports_and_self() {
+    let shape = ShapeFns::new()
+        .with_describe(|| Box::new("square"))
+        .with_same_as(|other: &ShapeFns| other.describe().to_string() == "square");
+    assert_eq!(shape.describe().to_string(), "square");
+    assert!(shape.same_as(&shape));
+}
+
+// the receivers are spelled out on purpose
+#[allow(clippy::needless_arbitrary_self_type)]
+#[portrait::make]
+trait Counter {
+    fn peek(self: &Self) -> u32;
+    fn bump(self: &mut Self, by: u32) -> u32;
+    fn into_total(self: Self) -> u32;
+}
+
+#[portrait::closures]
+impl Counter for CounterFns {}
+
+#[test]
+fn typed_receivers() {
+    let mut total = 0;
+    let label = String::from("total");
+
+    let mut counter = CounterFns::new()
+        .with_peek(|| 1)
+        .with_bump(move |by| {
+            total += by;
+            total
+        })
+        .with_into_total(move || label.len() as u32);
+
+    assert_eq!(counter.peek(), 1);
+    assert_eq!(counter.bump(2), 2);
+    assert_eq!(counter.bump(3), 5);
+    assert_eq!(counter.into_total(), 5);
+}
+
+#[portrait::make]
+trait Borrowed {
+    fn label(&self) -> &str;
+    fn first(self, items: &[u32]) -> &u32;
+    fn longest(&self, a: &str, b: &str) -> usize;
+}
+
+#[portrait::closures]
+impl Borrowed for BorrowedFns {}
+
+#[test]
+fn elided_lifetimes() {
+    let borrowed = BorrowedFns::new()
+        .with_label(|| "label")
+        .with_first(|items| &items[0])
+        .with_longest(|a, b| a.len().max(b.len()));
+
+    assert_eq!(borrowed.label(), "label");
+    assert_eq!(borrowed.longest("ab", "abc"), 3);
+    assert_eq!(*borrowed.first(&[4, 5]), 4);
+}
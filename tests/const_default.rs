@@ -0,0 +1,103 @@
+use portrait::ConstDefault;
+
+#[portrait::make]
+trait Limits {
+    const COUNT: usize;
+    const RATIO: f64;
+    const ENABLED: bool;
+    const SEPARATOR: char;
+    const NAME: &'static str;
+    const BYTES: &'static [u8];
+    const FALLBACK: Option<u32>;
+    const GRID: [[u8; 2]; 3];
+    const PAIR: (i8, (), (u16, bool));
+    const POINT: Point;
+    const RETRIES: u32;
+    const LABEL: &'static str;
+
+    fn count(&self) -> usize;
+}
+
+#[derive(Debug, PartialEq)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+impl ConstDefault for Point {
+    const DEFAULT: Self = Point { x: 1, y: 2 };
+}
+
+struct Stable;
+
+#[portrait::fill(portrait::default(const_default, RETRIES = 3, for &'static str = "label"))]
+impl Limits for Stable {}
+
+#[test]
+fn primitives() {
+    assert_eq!(Stable::COUNT, 0);
+    assert_eq!(Stable::RATIO, 0.0);
+    const { assert!(!Stable::ENABLED) };
+    assert_eq!(Stable::SEPARATOR, '\0');
+    assert_eq!(Stable::BYTES, &[] as &[u8]);
+    assert_eq!(Stable::FALLBACK, None);
+}
+
+#[test]
+fn compound() {
+    assert_eq!(Stable::GRID, [[0; 2]; 3]);
+    assert_eq!(Stable::PAIR, (0, (), (0, false)));
+    assert_eq!(Stable::POINT, Point { x: 1, y: 2 });
+}
+
+#[test]
+fn configured_values_take_precedence() {
+    assert_eq!(Stable::RETRIES, 3);
+    assert_eq!(Stable::NAME, "label");
+    assert_eq!(Stable::LABEL, "label");
+}
+
+#[test]
+fn functions_use_default() {
+    assert_eq!(Stable.count(), 0);
+}
+
+mod facade {
+    pub use portrait::ConstDefault as Reexported;
+
+    pub trait Sentinel {
+        const DEFAULT: Self;
+    }
+
+    impl Sentinel for u32 {
+        const DEFAULT: Self = u32::MAX;
+    }
+
+    impl Sentinel for &'static str {
+        const DEFAULT: Self = "?";
+    }
+}
+
+#[portrait::make]
+trait Markers {
+    const ID: u32;
+    const TAG: &'static str;
+}
+
+struct Reexported;
+
+#[portrait::fill(portrait::default(const_default(facade::Reexported)))]
+impl Markers for Reexported {}
+
+struct Sentinel;
+
+#[portrait::fill(portrait::default(const_default(crate::facade::Sentinel)))]
+impl Markers for Sentinel {}
+
+#[test]
+fn custom_const_default_path() {
+    assert_eq!(Reexported::ID, 0);
+    assert_eq!(Reexported::TAG, "");
+    assert_eq!(Sentinel::ID, u32::MAX);
+    assert_eq!(Sentinel::TAG, "?");
+}
@@ -0,0 +1,70 @@
+use std::future::Future;
+
+#[portrait::make]
+trait Config {
+    const RETRIES: u32;
+    const TIMEOUT: u64;
+    const LABEL: &'static str;
+    type Output;
+    type Items<T>;
+
+    fn name(&self) -> String;
+    fn description(&self) -> String;
+    fn limit(&self, scale: u64) -> u64;
+    fn enabled(&self) -> bool;
+    fn output(&self) -> Self::Output;
+    fn items(&self) -> Self::Items<u8>;
+    async fn fetch(&self) -> u64;
+    fn fetch_later(&self) -> impl Future<Output = u64>;
+}
+
+struct Defaults;
+
+#[portrait::fill(portrait::default(
+    RETRIES = 5,
+    name = String::from("defaults"),
+    for u64 = 1,
+    for String = String::from("n/a"),
+    for &'static str = "label",
+    types(Output = (u8, bool), Items = Vec<T>),
+))]
+impl Config for Defaults {}
+
+#[test]
+fn named_overrides() {
+    assert_eq!(Defaults::RETRIES, 5);
+    assert_eq!(Defaults.name(), "defaults");
+}
+
+#[test]
+fn type_rules() {
+    assert_eq!(Defaults::TIMEOUT, 1);
+    assert_eq!(Defaults::LABEL, "label");
+    assert_eq!(Defaults.description(), "n/a");
+    assert_eq!(Defaults.limit(10), 1);
+    assert!(!Defaults.enabled());
+}
+
+#[test]
+fn associated_types() {
+    let output: (u8, bool) = Defaults.output();
+    assert_eq!(output, (0, false));
+    let items: Vec<u8> = Defaults.items();
+    assert!(items.is_empty());
+}
+
+#[test]
+fn async_fn() {
+    let future = Defaults.fetch();
+    let mut future = std::pin::pin!(future);
+    let mut cx = std::task::Context::from_waker(std::task::Waker::noop());
+    assert_eq!(future.as_mut().poll(&mut cx), std::task::Poll::Ready(1));
+}
+
+#[test]
+fn impl_future() {
+    let future = Defaults.fetch_later();
+    let mut future = std::pin::pin!(future);
+    let mut cx = std::task::Context::from_waker(std::task::Waker::noop());
+    assert_eq!(future.as_mut().poll(&mut cx), std::task::Poll::Ready(1));
+}
@@ -0,0 +1,124 @@
+use std::pin::Pin;
+use std::task::{Context, Poll, Waker};
+
+#[portrait::make]
+trait Store {
+    type Error;
+
+    fn get(&self, key: u32) -> Result<u32, Self::Error>;
+    fn put(&mut self, key: u32, value: u32) -> Result<(), <Self as Store>::Error>;
+    fn next(&mut self) -> Option<Result<u32, Self::Error>>;
+    fn len(&self) -> usize;
+}
+
+#[derive(Debug, PartialEq)]
+struct InnerError(u32);
+
+struct Inner {
+    values: Vec<u32>,
+}
+
+impl Store for Inner {
+    type Error = InnerError;
+
+    fn get(&self, key: u32) -> Result<u32, InnerError> {
+        self.values.get(key as usize).copied().ok_or(InnerError(key))
+    }
+
+    fn put(&mut self, key: u32, value: u32) -> Result<(), InnerError> {
+        match self.values.get_mut(key as usize) {
+            Some(slot) => {
+                *slot = value;
+                Ok(())
+            }
+            None => Err(InnerError(key)),
+        }
+    }
+
+    fn next(&mut self) -> Option<Result<u32, InnerError>> {
+        self.values.pop().map(|value| if value == 0 { Err(InnerError(0)) } else { Ok(value) })
+    }
+
+    fn len(&self) -> usize { self.values.len() }
+}
+
+#[derive(Debug, PartialEq)]
+enum OuterError {
+    Inner(u32),
+}
+
+impl From<InnerError> for OuterError {
+    fn from(err: InnerError) -> Self { Self::Inner(err.0) }
+}
+
+struct Outer {
+    inner: Inner,
+}
+
+#[portrait::fill(portrait::delegate(Inner; self.inner; map_err = Into::into))]
+impl Store for Outer {
+    type Error = OuterError;
+}
+
+struct Counted {
+    inner: Inner,
+}
+
+#[portrait::fill(portrait::delegate(Inner; self.inner; map_err = |err: InnerError| err.0))]
+impl Store for Counted {
+    type Error = u32;
+}
+
+struct PollDelegate(Inner);
+
+#[portrait::make]
+trait Flush {
+    type Error;
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>>;
+}
+
+impl Flush for Inner {
+    type Error = InnerError;
+
+    fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), InnerError>> {
+        Poll::Ready(Err(InnerError(u32::MAX)))
+    }
+}
+
+#[portrait::fill(portrait::delegate(Inner; Pin::new(&mut self.get_mut().0); map_err = Into::into))]
+impl Flush for PollDelegate {
+    type Error = OuterError;
+}
+
+#[test]
+fn map_result() {
+    let mut outer = Outer { inner: Inner { values: vec![0, 1] } };
+    assert_eq!(outer.get(1), Ok(1));
+    assert_eq!(outer.get(2), Err(OuterError::Inner(2)));
+    assert_eq!(outer.put(3, 1), Err(OuterError::Inner(3)));
+    assert_eq!(outer.len(), 2);
+}
+
+#[test]
+fn map_option_result() {
+    let mut outer = Outer { inner: Inner { values: vec![0, 1] } };
+    assert_eq!(outer.next(), Some(Ok(1)));
+    assert_eq!(outer.next(), Some(Err(OuterError::Inner(0))));
+    assert_eq!(outer.next(), None);
+}
+
+#[test]
+fn map_closure() {
+    let mut counted = Counted { inner: Inner { values: vec![0] } };
+    assert_eq!(counted.get(5), Err(5));
+    assert_eq!(counted.next(), Some(Err(0)));
+}
+
+#[test]
+fn map_poll_result() {
+    let mut delegate = PollDelegate(Inner { values: vec![] });
+    let mut cx = Context::from_waker(Waker::noop());
+    let poll = Flush::poll_flush(Pin::new(&mut delegate), &mut cx);
+    assert_eq!(poll, Poll::Ready(Err(OuterError::Inner(u32::MAX))));
+}
@@ -0,0 +1,64 @@
+use std::collections::BTreeSet;
+
+#[portrait::make]
+trait Source {
+    #[portrait(derive_delegate(collect))]
+    fn to_vec(&self) -> Vec<u32>;
+    #[portrait(derive_delegate(collect))]
+    fn to_set(&self) -> BTreeSet<u32>;
+    #[portrait(derive_delegate(try = Some, collect))]
+    fn checked(&self) -> Option<Vec<u32>>;
+    #[portrait(derive_delegate(chain))]
+    fn items(&self) -> impl Iterator<Item = u32> + '_;
+}
+
+struct Numbers(Vec<u32>);
+
+impl Source for Numbers {
+    fn to_vec(&self) -> Vec<u32> { self.0.clone() }
+    fn to_set(&self) -> BTreeSet<u32> { self.0.iter().copied().collect() }
+    fn checked(&self) -> Option<Vec<u32>> { Some(self.to_vec()) }
+    fn items(&self) -> impl Iterator<Item = u32> + '_ { self.0.iter().copied() }
+}
+
+struct Range(u32, u32);
+
+impl Source for Range {
+    fn to_vec(&self) -> Vec<u32> { (self.0..self.1).collect() }
+    fn to_set(&self) -> BTreeSet<u32> { (self.0..self.1).collect() }
+    fn checked(&self) -> Option<Vec<u32>> { (self.0 <= self.1).then(|| self.to_vec()) }
+    fn items(&self) -> impl Iterator<Item = u32> + '_ { self.0..self.1 }
+}
+
+#[portrait::derive(Source with portrait::derive_delegate)]
+struct Sources {
+    numbers: Numbers,
+    range:   Range,
+    empty:   Numbers,
+}
+
+#[portrait::derive(Source with portrait::derive_delegate)]
+struct NoSources {}
+
+fn make_sources(range: Range) -> Sources {
+    Sources { numbers: Numbers(vec![1, 4]), range, empty: Numbers(Vec::new()) }
+}
+
+#[test]
+fn collect() {
+    let sources = make_sources(Range(3, 5));
+    assert_eq!(sources.to_vec(), [1, 4, 3, 4]);
+    assert_eq!(sources.to_set(), BTreeSet::from([1, 3, 4]));
+    assert_eq!(sources.checked(), Some(vec![1, 4, 3, 4]));
+    assert_eq!(make_sources(Range(5, 3)).checked(), None);
+
+    assert_eq!(NoSources {}.to_vec(), []);
+}
+
+#[test]
+fn chain() {
+    let sources = make_sources(Range(3, 5));
+    assert_eq!(sources.items().collect::<Vec<_>>(), [1, 4, 3, 4]);
+
+    assert_eq!(NoSources {}.items().count(), 0);
+}
@@ -0,0 +1,79 @@
+use std::cell::RefCell;
+
+thread_local! {
+    static LOG: RefCell<Vec<&'static str>> = const { RefCell::new(Vec::new()) };
+}
+
+fn take_log() -> Vec<&'static str> { LOG.with_borrow_mut(std::mem::take) }
+
+#[portrait::make]
+trait Hooks {
+    fn start(&mut self);
+    #[portrait(derive_delegate(order = reverse))]
+    fn shutdown(&mut self);
+    #[portrait(derive_delegate(order = priority))]
+    fn notify(&self);
+    #[portrait(derive_delegate(order = reverse, reduce = |a, b| format!("{a}{b}")))]
+    fn name(&self) -> String;
+}
+
+struct Hook(&'static str);
+
+impl Hooks for Hook {
+    fn start(&mut self) { LOG.with_borrow_mut(|log| log.push(self.0)) }
+    fn shutdown(&mut self) { LOG.with_borrow_mut(|log| log.push(self.0)) }
+    fn notify(&self) { LOG.with_borrow_mut(|log| log.push(self.0)) }
+    fn name(&self) -> String { self.0.to_string() }
+}
+
+#[portrait::derive(Hooks with portrait::derive_delegate)]
+struct Service {
+    database: Hook,
+    #[portrait(derive_delegate(priority = -1))]
+    metrics:  Hook,
+    #[portrait(derive_delegate(priority = 10))]
+    server:   Hook,
+    cache:    Hook,
+}
+
+#[portrait::derive(Hooks with portrait::derive_delegate)]
+enum Either {
+    Left(Hook, #[portrait(derive_delegate(priority = 1))] Hook),
+    Right { a: Hook, b: Hook },
+}
+
+#[test]
+fn struct_order() {
+    let mut service = Service {
+        database: Hook("database"),
+        metrics:  Hook("metrics"),
+        server:   Hook("server"),
+        cache:    Hook("cache"),
+    };
+
+    service.start();
+    assert_eq!(take_log(), ["database", "metrics", "server", "cache"]);
+
+    service.shutdown();
+    assert_eq!(take_log(), ["cache", "server", "metrics", "database"]);
+
+    service.notify();
+    assert_eq!(take_log(), ["server", "database", "cache", "metrics"]);
+
+    assert_eq!(service.name(), "cacheservermetricsdatabase");
+}
+
+#[test]
+fn enum_order() {
+    let mut left = Either::Left(Hook("a"), Hook("b"));
+    left.shutdown();
+    assert_eq!(take_log(), ["b", "a"]);
+    left.notify();
+    assert_eq!(take_log(), ["b", "a"]);
+
+    let mut right = Either::Right { a: Hook("a"), b: Hook("b") };
+    right.shutdown();
+    assert_eq!(take_log(), ["b", "a"]);
+    right.notify();
+    assert_eq!(take_log(), ["a", "b"]);
+}
@@ -0,0 +1,31 @@
+//! `Self`-typed parameters other than the receiver are destructured from the parameter itself.
+
+#[portrait::make]
+trait Compare {
+    #[portrait(derive_delegate(reduce = |a, b| a && b))]
+    fn same(&self, other: &Self) -> bool;
+    #[portrait(derive_delegate(reduce = |a, b| a + b))]
+    fn distance(self, other: Self) -> u32;
+}
+
+impl Compare for u32 {
+    fn same(&self, other: &Self) -> bool { self == other }
+    fn distance(self, other: Self) -> u32 { self.abs_diff(other) }
+}
+
+#[derive(Clone, Copy)]
+#[portrait::derive(Compare with portrait::derive_delegate)]
+struct Pair {
+    a: u32,
+    b: u32,
+}
+
+#[test]
+fn test_self_arg() {
+    let left = Pair { a: 1, b: 2 };
+    let right = Pair { a: 1, b: 5 };
+
+    assert!(left.same(&left));
+    assert!(!left.same(&right));
+    assert_eq!(left.distance(right), 3);
+}
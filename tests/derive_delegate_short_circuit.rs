@@ -0,0 +1,124 @@
+use std::cell::Cell;
+
+#[portrait::make]
+trait Lookup {
+    #[portrait(derive_delegate(first_some))]
+    fn get(&self, key: u32) -> Option<u32>;
+    #[portrait(derive_delegate(first_ok))]
+    fn parse(&self, key: u32) -> Result<u32, u32>;
+    #[portrait(derive_delegate(first_err))]
+    fn validate(&self, key: u32) -> Result<(), u32>;
+    #[portrait(derive_delegate(all))]
+    fn all_below(&self, key: u32) -> bool;
+    #[portrait(derive_delegate(any))]
+    fn any_equal(&self, key: u32) -> bool;
+    #[portrait(derive_delegate(until = |&value: &u32| value >= 10))]
+    fn weight(&self) -> u32;
+}
+
+struct Cache {
+    value: u32,
+    calls: Cell<usize>,
+}
+
+impl Cache {
+    fn new(value: u32) -> Self { Self { value, calls: Cell::new(0) } }
+
+    fn call(&self) -> u32 {
+        self.calls.set(self.calls.get() + 1);
+        self.value
+    }
+}
+
+impl Lookup for Cache {
+    fn get(&self, key: u32) -> Option<u32> { (self.call() == key).then_some(key) }
+    fn parse(&self, key: u32) -> Result<u32, u32> {
+        let value = self.call();
+        if value == key {
+            Ok(value)
+        } else {
+            Err(value)
+        }
+    }
+    fn validate(&self, key: u32) -> Result<(), u32> {
+        let value = self.call();
+        if value < key {
+            Ok(())
+        } else {
+            Err(value)
+        }
+    }
+    fn all_below(&self, key: u32) -> bool { self.call() < key }
+    fn any_equal(&self, key: u32) -> bool { self.call() == key }
+    fn weight(&self) -> u32 { self.call() }
+}
+
+#[portrait::derive(Lookup with portrait::derive_delegate)]
+struct Layers {
+    l1: Cache,
+    l2: Cache,
+    l3: Cache,
+}
+
+impl Layers {
+    fn new() -> Self { Self { l1: Cache::new(1), l2: Cache::new(20), l3: Cache::new(3) } }
+
+    fn calls(&self) -> [usize; 3] {
+        [self.l1.calls.get(), self.l2.calls.get(), self.l3.calls.get()]
+    }
+}
+
+#[test]
+fn first_some() {
+    let layers = Layers::new();
+    assert_eq!(layers.get(20), Some(20));
+    assert_eq!(layers.calls(), [1, 1, 0]);
+
+    let layers = Layers::new();
+    assert_eq!(layers.get(4), None);
+    assert_eq!(layers.calls(), [1, 1, 1]);
+}
+
+#[test]
+fn first_ok() {
+    let layers = Layers::new();
+    assert_eq!(layers.parse(1), Ok(1));
+    assert_eq!(layers.calls(), [1, 0, 0]);
+
+    let layers = Layers::new();
+    assert_eq!(layers.parse(4), Err(3));
+    assert_eq!(layers.calls(), [1, 1, 1]);
+}
+
+#[test]
+fn first_err() {
+    let layers = Layers::new();
+    assert_eq!(layers.validate(10), Err(20));
+    assert_eq!(layers.calls(), [1, 1, 0]);
+
+    let layers = Layers::new();
+    assert_eq!(layers.validate(30), Ok(()));
+    assert_eq!(layers.calls(), [1, 1, 1]);
+}
+
+#[test]
+fn all_any() {
+    let layers = Layers::new();
+    assert!(!layers.all_below(10));
+    assert_eq!(layers.calls(), [1, 1, 0]);
+
+    let layers = Layers::new();
+    assert!(layers.any_equal(1));
+    assert_eq!(layers.calls(), [1, 0, 0]);
+
+    let layers = Layers::new();
+    assert!(!layers.any_equal(4));
+    assert_eq!(layers.calls(), [1, 1, 1]);
+}
+
+#[test]
+fn until() {
+    let layers = Layers::new();
+    assert_eq!(layers.weight(), 20);
+    assert_eq!(layers.calls(), [1, 1, 0]);
+}
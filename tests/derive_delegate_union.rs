@@ -0,0 +1,96 @@
+use std::mem::ManuallyDrop;
+
+#[portrait::make]
+trait Shape {
+    fn area(&self) -> f64;
+    fn scale(&mut self, factor: f64);
+    #[portrait(derive_delegate(reduce = |a, b| a + b))]
+    fn sides(&self) -> usize;
+    #[portrait(derive_delegate(reduce = |a, b| a + b, reduce_base = 1))]
+    fn vertices(&self) -> usize;
+    fn doubled(&self) -> Self;
+}
+
+#[repr(u8)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Square,
+    Circle,
+}
+
+#[repr(C)]
+struct Square {
+    kind: Kind,
+    side: f64,
+}
+
+impl Shape for Square {
+    fn area(&self) -> f64 { self.side * self.side }
+    fn scale(&mut self, factor: f64) { self.side *= factor }
+    fn sides(&self) -> usize { 4 }
+    fn vertices(&self) -> usize { 4 }
+    fn doubled(&self) -> Self { Self { kind: self.kind, side: self.side * 2.0 } }
+}
+
+#[repr(C)]
+struct Circle {
+    kind:   Kind,
+    radius: f64,
+}
+
+impl Shape for Circle {
+    fn area(&self) -> f64 { 3.0 * self.radius * self.radius }
+    fn scale(&mut self, factor: f64) { self.radius *= factor }
+    fn sides(&self) -> usize { 0 }
+    fn vertices(&self) -> usize { 0 }
+    fn doubled(&self) -> Self { Self { kind: self.kind, radius: self.radius * 2.0 } }
+}
+
+impl<T: Shape> Shape for ManuallyDrop<T> {
+    fn area(&self) -> f64 { (**self).area() }
+    fn scale(&mut self, factor: f64) { (**self).scale(factor) }
+    fn sides(&self) -> usize { (**self).sides() }
+    fn vertices(&self) -> usize { (**self).vertices() }
+    fn doubled(&self) -> Self { ManuallyDrop::new((**self).doubled()) }
+}
+
+/// A C-style tagged union where every variant starts with the same `kind` field.
+#[repr(C)]
+#[portrait::derive(Shape with portrait::derive_delegate)]
+#[portrait(derive_delegate(union_tag = self.kind(), unsafe_union))]
+union RawShape {
+    #[portrait(derive_delegate(tag = Kind::Square))]
+    square: ManuallyDrop<Square>,
+    #[portrait(derive_delegate(tag = Kind::Circle))]
+    circle: ManuallyDrop<Circle>,
+}
+
+impl RawShape {
+    fn kind(&self) -> Kind {
+        // SAFETY: all variants are `repr(C)` and start with `kind`.
+        unsafe { self.square.kind }
+    }
+}
+
+#[test]
+fn union_delegate() {
+    let mut square =
+        RawShape { square: ManuallyDrop::new(Square { kind: Kind::Square, side: 2.0 }) };
+    assert_eq!(square.area(), 4.0);
+    assert_eq!(square.sides(), 4);
+    square.scale(2.0);
+    assert_eq!(square.area(), 16.0);
+
+    let circle = RawShape { circle: ManuallyDrop::new(Circle { kind: Kind::Circle, radius: 1.0 }) };
+    assert_eq!(circle.area(), 3.0);
+    assert_eq!(circle.sides(), 0);
+}
+
+#[test]
+fn union_single_field_aggregation() {
+    let square = RawShape { square: ManuallyDrop::new(Square { kind: Kind::Square, side: 2.0 }) };
+    // `reduce_base` is still applied to the only delegated field
+    assert_eq!(square.vertices(), 5);
+    // `Self` is reconstructed from the delegated field
+    assert_eq!(square.doubled().area(), 16.0);
+}
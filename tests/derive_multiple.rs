@@ -0,0 +1,56 @@
+#[portrait::make]
+trait Name {
+    #[portrait(derive_delegate(reduce = |a, b| format!("{a}{b}")))]
+    fn name(&self) -> String;
+}
+
+#[portrait::make]
+trait Count {
+    #[portrait(derive_delegate(reduce = |a, b| a + b))]
+    fn count(&self) -> usize;
+}
+
+#[portrait::make]
+trait Reset {
+    fn reset(&mut self);
+}
+
+struct Leaf(&'static str);
+
+impl Name for Leaf {
+    fn name(&self) -> String { self.0.to_string() }
+}
+
+impl Count for Leaf {
+    fn count(&self) -> usize { 1 }
+}
+
+#[portrait::fill(portrait::default)]
+impl Reset for Leaf {}
+
+#[portrait::derive(Name, Count, Reset with portrait::derive_delegate)]
+struct Grouped {
+    a: Leaf,
+    b: Leaf,
+}
+
+#[portrait::derive(Name with portrait::derive_delegate, Count with portrait::derive_delegate)]
+struct Separate {
+    a: Leaf,
+    b: Leaf,
+}
+
+#[test]
+fn grouped() {
+    let mut grouped = Grouped { a: Leaf("a"), b: Leaf("b") };
+    assert_eq!(grouped.name(), "ab");
+    assert_eq!(grouped.count(), 2);
+    grouped.reset();
+}
+
+#[test]
+fn separate() {
+    let separate = Separate { a: Leaf("a"), b: Leaf("b") };
+    assert_eq!(separate.name(), "ab");
+    assert_eq!(separate.count(), 2);
+}
@@ -0,0 +1,24 @@
+#![allow(dead_code)]
+
+mod def {
+    #[portrait::make(export = facade)]
+    pub trait Local {
+        fn value(&self) -> u32;
+    }
+}
+
+mod impls {
+    use crate::def::Local;
+
+    pub struct Impl;
+
+    #[portrait::fill(portrait::default)]
+    impl Local for Impl {}
+}
+
+#[test]
+fn test_export_facade() {
+    use def::Local;
+
+    assert_eq!(impls::Impl.value(), 0);
+}
@@ -0,0 +1,70 @@
+#![allow(dead_code, async_fn_in_trait)]
+
+use std::future::Future;
+use std::pin::pin;
+use std::task::{Context, Poll, Waker};
+
+pub struct Client<T> {
+    prefix: String,
+    items:  Vec<u32>,
+    marker: T,
+}
+
+#[portrait::extract(pub trait ClientApi, name = client_api_portrait)]
+impl<T: Clone> Client<T> {
+    /// Returns the prefixed path.
+    pub fn path(&self, path: &str) -> String { format!("{}{path}", self.prefix) }
+
+    pub fn push(&mut self, (first, second): (u32, u32)) {
+        self.items.push(first);
+        self.items.push(second);
+    }
+
+    pub fn parse<U: std::str::FromStr>(&self, input: &str) -> Option<U> { input.parse().ok() }
+
+    pub fn into_items(mut self) -> Vec<u32> {
+        self.items.reverse();
+        self.items
+    }
+
+    pub(crate) async fn len(&self) -> usize { self.items.len() }
+
+    #[allow(dead_code)]
+    fn private(&self) {}
+}
+
+struct Mock;
+
+#[portrait::fill(portrait::default)]
+impl ClientApi for Mock {
+    fn path(&self, path: &str) -> String { format!("mock:{path}") }
+}
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let mut context = Context::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+    }
+}
+
+fn use_api(mut api: impl ClientApi) -> (String, Option<u32>, usize) {
+    api.push(Default::default());
+    (api.path("/users"), api.parse::<u32>("42"), block_on(api.len()))
+}
+
+#[test]
+fn extracted_trait() {
+    let client = Client { prefix: "https://example.com".to_string(), items: vec![1], marker: () };
+    assert_eq!(use_api(client), ("https://example.com/users".to_string(), Some(42), 3),);
+
+    let client = Client { prefix: String::new(), items: vec![1, 2], marker: () };
+    assert_eq!(ClientApi::into_items(client), [2, 1]);
+}
+
+#[test]
+fn mock() {
+    assert_eq!(use_api(Mock), ("mock:/users".to_string(), None, 0));
+}
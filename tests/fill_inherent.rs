@@ -0,0 +1,98 @@
+#![allow(clippy::manual_async_fn)]
+
+use std::future::Future;
+use std::pin::pin;
+use std::task::{Context, Poll, Waker};
+
+mod api {
+    #[portrait::make]
+    pub trait Storage {
+        type Key;
+
+        const CAPACITY: usize;
+
+        fn new() -> Self;
+        fn get(&self, key: &Self::Key) -> Option<u32>;
+        fn insert(&mut self, key: Self::Key, value: u32) -> Option<u32>;
+        fn insert_pair(&mut self, (key, value): (Self::Key, u32)) -> Option<u32> {
+            self.insert(key, value)
+        }
+        fn extend<I: IntoIterator<Item = (Self::Key, u32)>>(&mut self, items: I) {
+            for (key, value) in items {
+                self.insert(key, value);
+            }
+        }
+        fn contains(&self, key: impl AsRef<Self::Key>) -> bool { self.get(key.as_ref()).is_some() }
+        async fn len(&self) -> usize;
+        fn clear(&mut self);
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Key(u8);
+
+impl AsRef<Key> for Key {
+    fn as_ref(&self) -> &Key { self }
+}
+
+struct Store {
+    values: Vec<(u8, u32)>,
+}
+
+impl api::Storage for Store {
+    type Key = Key;
+
+    const CAPACITY: usize = 8;
+
+    fn new() -> Self { Self { values: Vec::new() } }
+    fn get(&self, key: &Key) -> Option<u32> {
+        self.values.iter().find(|(k, _)| *k == key.0).map(|&(_, v)| v)
+    }
+    fn insert(&mut self, key: Key, value: u32) -> Option<u32> {
+        let old = self.get(&key);
+        self.values.retain(|(k, _)| *k != key.0);
+        self.values.push((key.0, value));
+        old
+    }
+    fn len(&self) -> impl Future<Output = usize> { async { self.values.len() } }
+    fn clear(&mut self) { self.values.clear() }
+}
+
+#[portrait::fill_inherent(@EXCLUDE(clear) pub(crate) api::Storage)]
+impl Store {
+    fn contains(&self, key: u8) -> bool { self.values.iter().any(|&(k, _)| k == key) }
+}
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let mut context = Context::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+    }
+}
+
+#[test]
+fn forwarded() {
+    assert_eq!(Store::CAPACITY, 8);
+
+    let mut store = Store::new();
+    assert_eq!(store.insert(Key(1), 2), None);
+    assert_eq!(store.insert_pair((Key(1), 3)), Some(2));
+    store.extend([(Key(2), 4), (Key(3), 5)]);
+    assert_eq!(store.get(&Key(2)), Some(4));
+    assert_eq!(block_on(store.len()), 3);
+}
+
+#[test]
+fn overridden() {
+    let mut store = Store::new();
+    store.insert(Key(1), 2);
+    assert!(store.contains(1));
+    assert!(api::Storage::contains(&store, Key(1)));
+
+    // excluded items are only accessible through the trait
+    api::Storage::clear(&mut store);
+    assert!(!store.contains(1));
+}
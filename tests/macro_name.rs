@@ -0,0 +1,32 @@
+//! Identical exported portraits in different modules are distinguished by their derived module name.
+
+#![allow(dead_code)]
+
+pub mod a {
+    #[portrait::make]
+    pub trait Same {
+        fn value(&self) -> u32;
+    }
+}
+
+pub mod b {
+    // without a different name, both traits would export the same portrait macro
+    #[portrait::make(name = other_same_portrait)]
+    pub trait Same {
+        fn value(&self) -> u32;
+    }
+}
+
+struct Both;
+
+#[portrait::fill(portrait::default)]
+impl a::Same for Both {}
+
+#[portrait::fill(portrait::default)]
+impl b::Same for Both {}
+
+#[test]
+fn test_identical_portraits() {
+    assert_eq!(a::Same::value(&Both), 0);
+    assert_eq!(b::Same::value(&Both), 0);
+}
@@ -0,0 +1,96 @@
+use std::future::Future;
+use std::pin::pin;
+use std::task::{Context, Poll, Waker};
+
+#[portrait::make]
+trait Number: Sized {
+    const ZERO: Self;
+    const NAME: &'static str;
+    type Digits;
+
+    fn new(value: i64) -> Self;
+    fn add(&self, other: &Self) -> Self;
+    fn negate(&mut self);
+    fn checked_div(self, other: Self) -> Result<Self, String>;
+    fn parse(input: &str) -> Option<Self>;
+    fn digits(&self) -> Self::Digits;
+    fn sum(items: Vec<Self>) -> Self;
+    fn split(self) -> Vec<Self>;
+    async fn load(value: i64) -> Self;
+}
+
+#[derive(Debug, PartialEq)]
+struct Int(i64);
+
+impl Number for Int {
+    const ZERO: Self = Int(0);
+    const NAME: &'static str = "int";
+    type Digits = Vec<u8>;
+
+    fn new(value: i64) -> Self { Int(value) }
+    fn add(&self, other: &Self) -> Self { Int(self.0 + other.0) }
+    fn negate(&mut self) { self.0 = -self.0; }
+    fn checked_div(self, other: Self) -> Result<Self, String> {
+        self.0.checked_div(other.0).map(Int).ok_or_else(|| "division by zero".into())
+    }
+    fn parse(input: &str) -> Option<Self> { input.parse().ok().map(Int) }
+    fn digits(&self) -> Vec<u8> { self.0.to_string().bytes().map(|b| b - b'0').collect() }
+    fn sum(items: Vec<Self>) -> Self { Int(items.into_iter().map(|item| item.0).sum()) }
+    fn split(self) -> Vec<Self> { vec![Int(self.0 / 2), Int(self.0 - self.0 / 2)] }
+    async fn load(value: i64) -> Self { Int(value) }
+}
+
+#[derive(Debug, PartialEq)]
+struct Tuple(Int);
+
+#[portrait::fill(portrait::newtype(Int; self.0))]
+impl Number for Tuple {}
+
+#[derive(Debug, PartialEq)]
+struct Named {
+    inner: Int,
+}
+
+impl Named {
+    const fn wrap(inner: Int) -> Self { Self { inner } }
+}
+
+#[portrait::fill(portrait::newtype(Int; self.inner; Named::wrap))]
+impl Number for Named {
+    const NAME: &'static str = "named";
+}
+
+fn block_on<F: Future>(fut: F) -> F::Output {
+    let mut fut = pin!(fut);
+    match fut.as_mut().poll(&mut Context::from_waker(Waker::noop())) {
+        Poll::Ready(output) => output,
+        Poll::Pending => unreachable!("test futures never suspend"),
+    }
+}
+
+#[test]
+fn test_tuple_newtype() {
+    assert_eq!(Tuple::ZERO, Tuple(Int(0)));
+    assert_eq!(Tuple::NAME, "int");
+    assert_eq!(Tuple::new(2).add(&Tuple::new(3)), Tuple(Int(5)));
+
+    let mut value = Tuple::new(4);
+    value.negate();
+    assert_eq!(value, Tuple(Int(-4)));
+
+    assert_eq!(Tuple::new(6).checked_div(Tuple::new(3)), Ok(Tuple(Int(2))));
+    assert!(Tuple::new(6).checked_div(Tuple::ZERO).is_err());
+    assert_eq!(Tuple::parse("12"), Some(Tuple(Int(12))));
+    assert_eq!(Tuple::new(12).digits(), vec![1, 2]);
+    assert_eq!(Tuple::sum(vec![Tuple::new(1), Tuple::new(2)]), Tuple(Int(3)));
+    assert_eq!(Tuple::new(5).split(), vec![Tuple(Int(2)), Tuple(Int(3))]);
+    assert_eq!(block_on(Tuple::load(7)), Tuple(Int(7)));
+}
+
+#[test]
+fn test_named_newtype() {
+    assert_eq!(Named::ZERO, Named { inner: Int(0) });
+    assert_eq!(Named::NAME, "named");
+    assert_eq!(Named::new(2).add(&Named::new(3)), Named { inner: Int(5) });
+    assert_eq!(Named::parse("x"), None);
+}
@@ -0,0 +1,84 @@
+use std::cell::RefCell;
+
+#[portrait::make]
+trait Recorder {
+    const NAME: &'static str;
+    type Output<T>
+    where
+        T: Clone;
+
+    fn unit(&self);
+    fn add(&mut self, a: u32, (b, c): (u32, u32)) -> u32 { a + b + c }
+    fn generic<T: std::fmt::Debug>(&self, value: T) -> Option<T>;
+    fn consume(self) -> String;
+    fn associated(x: i32) -> i32;
+}
+
+thread_local! {
+    static CALLS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+fn take_calls() -> Vec<String> { CALLS.with_borrow_mut(std::mem::take) }
+
+macro_rules! record {
+    (@ARGS {$prefix:literal} @fn $(#[$attr:meta])* {$($sig:tt)*} $name:ident ($($self_:ident)?) ($($arg:ident: $arg_ty:ty),*) -> $ret:ty;) => {
+        $($sig)* {
+            $(let _ = &$self_;)?
+            let args: Vec<String> = vec![$(format!("{:?}", $arg)),*];
+            CALLS.with_borrow_mut(|calls| {
+                calls.push(format!("{}{}({})", $prefix, stringify!($name), args.join(", ")))
+            });
+            <$ret as Default>::default()
+        }
+    };
+    (@ARGS {$prefix:literal} @const $(#[$attr:meta])* $name:ident: $ty:ty;) => {
+        const $name: $ty = $prefix;
+    };
+    (@ARGS {$prefix:literal} @type $(#[$attr:meta])* $name:ident [$($generics:tt)*] where [$($where:tt)*];) => {
+        type $name<$($generics)*> = Vec<T> where $($where)*;
+    };
+}
+
+#[derive(Default)]
+struct Filled(u32);
+
+#[portrait::fill(portrait::rules(record, "filled::"))]
+impl Recorder for Filled {
+    fn add(&mut self, a: u32, (b, c): (u32, u32)) -> u32 {
+        self.0 += a + b + c;
+        self.0
+    }
+}
+
+struct AllItems;
+
+impl Recorder for AllItems {
+    Recorder! { @RULES {record} @ARGS {"all::"} }
+}
+
+#[test]
+fn filler() {
+    assert_eq!(Filled::NAME, "filled::");
+    let _: <Filled as Recorder>::Output<u8> = Vec::new();
+
+    let mut filled = Filled(0);
+    filled.unit();
+    assert_eq!(filled.add(1, (2, 3)), 6);
+    assert_eq!(filled.generic("x"), None);
+    assert_eq!(Filled::associated(5), 0);
+    assert_eq!(filled.consume(), "");
+    assert_eq!(
+        take_calls(),
+        ["filled::unit()", "filled::generic(\"x\")", "filled::associated(5)", "filled::consume()"]
+    );
+}
+
+#[test]
+fn all_items() {
+    assert_eq!(AllItems::NAME, "all::");
+
+    let mut all = AllItems;
+    all.unit();
+    assert_eq!(all.add(1, (2, 3)), 0);
+    assert_eq!(take_calls(), ["all::unit()", "all::add(1, (2, 3))"]);
+}
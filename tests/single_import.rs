@@ -0,0 +1,72 @@
+#![allow(dead_code)]
+
+mod types {
+    #[derive(Debug, Default, PartialEq)]
+    pub struct Key(pub u32);
+}
+
+mod def {
+    // relative imports are re-exported from the derived modules
+    pub use std::collections::BTreeMap;
+
+    pub use crate::types::Key;
+
+    #[portrait::make]
+    pub trait Plain {
+        fn plain(&self) -> u32;
+    }
+
+    #[portrait::make(import(crate::types::Key, std::collections::BTreeMap))]
+    pub trait Lookup {
+        fn key(&self) -> Key;
+        fn map(&self) -> BTreeMap<u32, Key>;
+    }
+
+    #[portrait::make(module = crate::def, import(super::super::Key, super::super::BTreeMap))]
+    pub trait Relative {
+        fn relative_key(&self) -> Key;
+        fn relative_map(&self) -> BTreeMap<u32, Key>;
+    }
+
+    #[portrait::make(module = crate::def, auto_imports)]
+    pub trait Auto {
+        fn auto_key(&self) -> Key;
+        fn auto_map(&self) -> BTreeMap<u32, Key>;
+    }
+}
+
+mod impls {
+    use crate::def::{Auto, Lookup, Plain, Relative};
+
+    #[derive(Default)]
+    pub struct Inner;
+
+    #[portrait::fill(portrait::default)]
+    impl Plain for Inner {}
+
+    #[portrait::fill(portrait::default)]
+    impl Lookup for Inner {}
+
+    #[portrait::fill(portrait::default)]
+    impl Relative for Inner {}
+
+    #[portrait::fill(portrait::default)]
+    impl Auto for Inner {}
+
+    #[portrait::derive(Plain, Lookup, Relative, Auto with portrait::derive_delegate)]
+    pub struct Outer(pub Inner);
+}
+
+#[test]
+fn test_single_import() {
+    use def::{Auto, Lookup, Plain, Relative};
+
+    let outer = impls::Outer(impls::Inner);
+    assert_eq!(outer.plain(), 0);
+    assert_eq!(outer.key(), types::Key(0));
+    assert!(outer.map().is_empty());
+    assert_eq!(outer.relative_key(), types::Key(0));
+    assert!(outer.relative_map().is_empty());
+    assert_eq!(outer.auto_key(), types::Key(0));
+    assert!(outer.auto_map().is_empty());
+}
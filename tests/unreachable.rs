@@ -0,0 +1,47 @@
+#![allow(dead_code)]
+
+use std::pin::Pin;
+
+#[portrait::make]
+trait Backend {
+    const NAME: &'static str;
+    type Handle;
+    type Error;
+    type Items<T>;
+
+    fn new() -> Self;
+    fn connect(&self, address: &str) -> Result<Self::Handle, Self::Error>;
+    fn reset(&mut self, retries: u32);
+    fn close(self) -> u32;
+    fn boxed(self: Box<Self>) -> bool;
+    fn pinned(self: Pin<&mut Self>, items: Self::Items<u8>);
+    async fn flush(&self) -> u64;
+}
+
+enum Never {}
+
+#[portrait::fill(portrait::unreachable(NAME = "never", new = panic!("cannot construct"), types(Error = String)))]
+impl Backend for Never {}
+
+static_assertions::assert_type_eq_all!(<Never as Backend>::Handle, Never);
+static_assertions::assert_type_eq_all!(<Never as Backend>::Error, String);
+static_assertions::assert_type_eq_all!(<Never as Backend>::Items<u8>, Never);
+
+#[test]
+fn constants() {
+    assert_eq!(Never::NAME, "never");
+}
+
+#[test]
+#[should_panic = "cannot construct"]
+fn explicit_body() { Never::new(); }
+
+#[test]
+fn generic_usage() {
+    fn connect_all<B: Backend>(backends: &mut [B]) -> usize {
+        backends.iter_mut().filter(|backend| backend.connect("localhost").is_ok()).count()
+    }
+
+    let mut backends: [Never; 0] = [];
+    assert_eq!(connect_all(&mut backends), 0);
+}